@@ -0,0 +1,461 @@
+pub mod lock;
+pub mod solver;
+pub mod stats;
+pub mod trace;
+
+use common::args::{Args, Flag};
+use common::numeric::{Num, NumericMode};
+use std::io;
+
+fn invalid_data(msg: String) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, msg)
+}
+
+// one rotation: L68 or -68 turn left, R48 or +48 turn right, case insensitive
+pub fn parse_instruction(token: &str) -> io::Result<i64> {
+    let mut chars = token.chars();
+    let sign = match chars.next() {
+        Some('L' | 'l' | '-') => -1,
+        Some('R' | 'r' | '+') => 1,
+        _ => {
+            return Err(invalid_data(format!(
+                "invalid instruction '{token}', expected L<n>, R<n>, -<n> or +<n>"
+            )))
+        }
+    };
+    let digits = chars.as_str();
+    if digits.is_empty() || !digits.bytes().all(|b| b.is_ascii_digit()) {
+        return Err(invalid_data(format!(
+            "invalid instruction '{token}', expected digits after the direction"
+        )));
+    }
+    // i64::MIN is refused too, so that any rotation has a magnitude fitting in an i64
+    digits
+        .parse::<i64>()
+        .ok()
+        .map(|value| sign * value)
+        .ok_or_else(|| invalid_data(format!("rotation '{token}' is too big")))
+}
+
+// comma separated rotations, a blank line gives no rotation
+pub fn parse_line(line: &str, line_number: usize) -> io::Result<Vec<i64>> {
+    line.split(',')
+        .map(str::trim)
+        .filter(|token| !token.is_empty())
+        .map(|token| {
+            parse_instruction(token)
+                .map_err(|err| io::Error::new(err.kind(), format!("line {line_number}: {err}")))
+        })
+        .collect()
+}
+
+pub fn parse_input(input: &str) -> io::Result<Vec<i64>> {
+    let mut rotations = Vec::new();
+    for (idx, line) in input.lines().enumerate() {
+        rotations.extend(parse_line(line, idx + 1)?);
+    }
+    Ok(rotations)
+}
+
+pub fn format_instruction(val: i64) -> String {
+    if val < 0 {
+        format!("L{}", val.unsigned_abs())
+    } else {
+        format!("R{val}")
+    }
+}
+
+pub fn roll_value(current: i32, val: i64) -> io::Result<(i32, i64)> {
+    Dial::default().roll(current, val)
+}
+
+fn invalid_input(msg: String) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidInput, msg)
+}
+
+// a safe dial with `size` positions numbered from 0, the cursor starts at `start`
+// and every click on one of the `targets` is counted
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Dial {
+    // only built through `Dial::new`, which checks them
+    size: i32,
+    start: i32,
+    targets: Vec<i32>,
+}
+
+impl Default for Dial {
+    fn default() -> Self {
+        Dial {
+            size: 100,
+            start: 50,
+            targets: vec![0],
+        }
+    }
+}
+
+impl Dial {
+    pub fn new(size: i32, start: i32, mut targets: Vec<i32>) -> io::Result<Dial> {
+        if size <= 0 {
+            return Err(invalid_input(format!(
+                "dial size must be positive, got {size}"
+            )));
+        }
+        for pos in targets.iter().chain([&start]) {
+            if !(0..size).contains(pos) {
+                return Err(invalid_input(format!(
+                    "position {pos} is outside of a dial of size {size}"
+                )));
+            }
+        }
+        targets.sort_unstable();
+        targets.dedup();
+        Ok(Dial {
+            size,
+            start,
+            targets,
+        })
+    }
+
+    pub fn size(&self) -> i32 {
+        self.size
+    }
+
+    pub fn start(&self) -> i32 {
+        self.start
+    }
+
+    // sorted and without duplicates
+    pub fn targets(&self) -> &[i32] {
+        &self.targets
+    }
+
+    pub const FLAGS: &'static [Flag] = &[
+        Flag::value("--size"),
+        Flag::value("--start"),
+        Flag::value("--target"),
+    ];
+
+    // `--size <n>`, `--start <n>` and `--target <n>[,<n>..]`, the puzzle dial without them
+    pub fn from_args(args: &Args) -> io::Result<Dial> {
+        let default = Dial::default();
+        let size = args.parse_value("--size")?.unwrap_or(default.size);
+        let start = args.parse_value("--start")?.unwrap_or(default.start);
+        let targets = match args.value("--target") {
+            Some(value) => value
+                .split(',')
+                .map(|v| {
+                    v.trim().parse().map_err(|err| {
+                        invalid_input(format!("invalid target '{v}' in '{value}': {err}"))
+                    })
+                })
+                .collect::<io::Result<_>>()?,
+            None => default.targets,
+        };
+        Dial::new(size, start, targets)
+    }
+
+    // number of positions x in (from, to] (or [to, from) going left) with x = target modulo size,
+    // counted with floor divisions so any rotation is O(1) per target
+    fn passes(&self, from: i128, to: i128, target: i32) -> i128 {
+        let size = self.size as i128;
+        let target = target as i128;
+        if to >= from {
+            (to - target).div_euclid(size) - (from - target).div_euclid(size)
+        } else {
+            (from - 1 - target).div_euclid(size) - (to - 1 - target).div_euclid(size)
+        }
+    }
+
+    // turn the dial by `val` (negative going left), returns the new position
+    // and the number of clicks on a target, which is at most |val|
+    pub fn roll(&self, current: i32, val: i64) -> io::Result<(i32, i64)> {
+        if val == i64::MIN {
+            return Err(invalid_input(format!(
+                "rotation {val} is too large, its magnitude must fit in an i64"
+            )));
+        }
+        // widen so a rotation close to i64::MAX cannot overflow the sum
+        let from = current as i128;
+        let to = from + val as i128;
+        let clicks: i128 = self.targets.iter().map(|&t| self.passes(from, to, t)).sum();
+        Ok((to.rem_euclid(self.size as i128) as i32, clicks as i64))
+    }
+
+    pub fn counter(&self, mode: NumericMode) -> DialCounter {
+        DialCounter {
+            dial: self.clone(),
+            cursor: self.start,
+            landed: Num::new(mode, 0),
+            clicks: Num::new(mode, 0),
+            mode,
+        }
+    }
+}
+
+// both answers in a single pass: part 1 counts the rotations ending on a target,
+// part 2 every click passing through one, `roll` returns the clicks of one rotation
+pub struct DialCounter {
+    pub dial: Dial,
+    pub cursor: i32,
+    pub landed: Num,
+    pub clicks: Num,
+    mode: NumericMode,
+}
+
+impl DialCounter {
+    pub fn roll(&mut self, val: i64) -> io::Result<i64> {
+        let clicks;
+        (self.cursor, clicks) = self.dial.roll(self.cursor, val)?;
+        if self.dial.targets.contains(&self.cursor) {
+            self.landed = self.landed.try_add(&Num::new(self.mode, 1))?;
+        }
+        self.clicks = self.clicks.try_add(&Num::new(self.mode, clicks))?;
+        Ok(clicks)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_line() {
+        assert_eq!(parse_line("L68", 1).unwrap(), vec![-68]);
+        assert_eq!(parse_line("L30", 1).unwrap(), vec![-30]);
+        assert_eq!(parse_line("R48", 1).unwrap(), vec![48]);
+        assert_eq!(parse_line("l5", 1).unwrap(), vec![-5]);
+        assert_eq!(parse_line(" r60 ", 1).unwrap(), vec![60]);
+        assert_eq!(parse_line("+12, -7,L3,", 1).unwrap(), vec![12, -7, -3]);
+        assert_eq!(parse_line("   ", 1).unwrap(), vec![]);
+        assert_eq!(parse_line("L2147483648", 1).unwrap(), vec![-2147483648]);
+        assert_eq!(
+            parse_line("R9223372036854775807", 1).unwrap(),
+            vec![i64::MAX]
+        );
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        for bad in [
+            "L",
+            "X12",
+            "L+5",
+            "R1.5",
+            "é3",
+            "R 3",
+            "L9223372036854775808",
+        ] {
+            let err = parse_line(&format!("R1,{bad}"), 7).unwrap_err();
+            assert_eq!(err.kind(), io::ErrorKind::InvalidData);
+            assert!(err.to_string().starts_with("line 7: "), "{err}");
+        }
+    }
+
+    #[test]
+    fn test_parse_input() {
+        let input = "L68\nL30\n\nR48,L5\nr60\n";
+        assert_eq!(parse_input(input).unwrap(), vec![-68, -30, 48, -5, 60]);
+        let err = parse_input("L68\n\nL\n").unwrap_err();
+        assert!(err.to_string().starts_with("line 3: "), "{err}");
+        assert_eq!(format_instruction(-68), "L68");
+        assert_eq!(format_instruction(48), "R48");
+        assert_eq!(format_instruction(-i64::MAX), "L9223372036854775807");
+    }
+
+    #[test]
+    fn test_roll_value() {
+        let mut cursor = 50;
+        (cursor, _) = roll_value(cursor, -68).unwrap();
+        assert_eq!(cursor, 82);
+
+        (cursor, _) = roll_value(cursor, -30).unwrap();
+        assert_eq!(cursor, 52);
+
+        (cursor, _) = roll_value(cursor, 48).unwrap();
+        assert_eq!(cursor, 0);
+
+        (cursor, _) = roll_value(cursor, -5).unwrap();
+        assert_eq!(cursor, 95);
+
+        (cursor, _) = roll_value(cursor, 60).unwrap();
+        assert_eq!(cursor, 55);
+
+        (cursor, _) = roll_value(cursor, -55).unwrap();
+        assert_eq!(cursor, 0);
+
+        (cursor, _) = roll_value(cursor, -1).unwrap();
+        assert_eq!(cursor, 99);
+
+        (cursor, _) = roll_value(cursor, -99).unwrap();
+        assert_eq!(cursor, 0);
+
+        (cursor, _) = roll_value(cursor, 14).unwrap();
+        assert_eq!(cursor, 14);
+
+        (cursor, _) = roll_value(cursor, -82).unwrap();
+        assert_eq!(cursor, 32);
+
+        (cursor, _) = roll_value(0, 100).unwrap();
+        assert_eq!(cursor, 0);
+    }
+
+    #[test]
+    fn test_clicks() {
+        let mut cursor = 50;
+        let mut click;
+        (cursor, click) = roll_value(cursor, 50).unwrap();
+        assert_eq!(cursor, 0);
+        assert_eq!(click, 1);
+        (cursor, click) = roll_value(cursor, 50).unwrap();
+        assert_eq!(cursor, 50);
+        assert_eq!(click, 0);
+        (cursor, click) = roll_value(cursor, 50).unwrap();
+        assert_eq!(cursor, 0);
+        assert_eq!(click, 1);
+        (cursor, click) = roll_value(cursor, 50).unwrap();
+        assert_eq!(cursor, 50);
+        assert_eq!(click, 0);
+        (cursor, click) = roll_value(cursor, 100).unwrap();
+        assert_eq!(cursor, 50);
+        assert_eq!(click, 1);
+        (cursor, click) = roll_value(cursor, -100).unwrap();
+        assert_eq!(cursor, 50);
+        assert_eq!(click, 1);
+        (cursor, click) = roll_value(cursor, -50).unwrap();
+        assert_eq!(cursor, 0);
+        assert_eq!(click, 1);
+        (cursor, click) = roll_value(50, -150).unwrap();
+        assert_eq!(cursor, 0);
+        assert_eq!(click, 2);
+        (cursor, click) = roll_value(50, -250).unwrap();
+        assert_eq!(cursor, 0);
+        assert_eq!(click, 3);
+        (cursor, click) = roll_value(99, i32::MAX as i64).unwrap();
+        assert_eq!(cursor, 46);
+        assert_eq!(click, 21474837);
+    }
+
+    #[test]
+    fn test_zero_counter() {
+        let mut counter = Dial::default().counter(NumericMode::Checked);
+        for val in [-68, -30, 48, -5, 60, -55, -1, -99, 14, -82] {
+            counter.roll(val).unwrap();
+        }
+        assert_eq!(counter.cursor, 32);
+        assert_eq!(counter.landed, 3);
+        assert_eq!(counter.clicks, 6);
+
+        // passing zero without stopping there only counts for part 2
+        let mut counter = Dial::default().counter(NumericMode::Checked);
+        counter.roll(-250).unwrap();
+        counter.roll(150).unwrap();
+        assert_eq!(counter.landed, 1);
+        assert_eq!(counter.clicks, 4);
+    }
+
+    // click by click reference of a rotation
+    fn slow_roll(dial: &Dial, current: i32, val: i64) -> (i32, i64) {
+        let step = val.signum() as i32;
+        let mut cursor = current;
+        let mut clicks = 0;
+        for _ in 0..val.abs() {
+            cursor = (cursor + step).rem_euclid(dial.size);
+            if dial.targets.contains(&cursor) {
+                clicks += 1;
+            }
+        }
+        (cursor, clicks)
+    }
+
+    #[test]
+    fn test_small_dials_exhaustive() {
+        for size in 1..=7 {
+            for mask in 1..(1 << size) {
+                let targets = (0..size).filter(|t| mask & (1 << t) != 0).collect();
+                let dial = Dial::new(size, 0, targets).unwrap();
+                for current in 0..size {
+                    for val in -3 * size as i64..=3 * size as i64 {
+                        assert_eq!(
+                            dial.roll(current, val).unwrap(),
+                            slow_roll(&dial, current, val),
+                            "{dial:?} from {current} by {val}"
+                        );
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_dial_counter() {
+        let dial = Dial::new(10, 5, vec![0, 5]).unwrap();
+        let mut counter = dial.counter(NumericMode::Checked);
+        assert_eq!(counter.roll(5).unwrap(), 1);
+        assert_eq!(counter.roll(-25).unwrap(), 5);
+        assert_eq!(counter.roll(3).unwrap(), 0);
+        assert_eq!(counter.cursor, 8);
+        assert_eq!(counter.landed, 2);
+        assert_eq!(counter.clicks, 6);
+    }
+
+    #[test]
+    fn test_dial_args() {
+        let args = |a: &[&str]| {
+            Args::parse(a.iter().map(|s| s.to_string()), Dial::FLAGS)
+                .and_then(|args| Dial::from_args(&args))
+        };
+        assert_eq!(args(&[]).unwrap(), Dial::default());
+        assert_eq!(
+            args(&["--size", "10", "--start=3", "--target", "7,2,7"]).unwrap(),
+            Dial::new(10, 3, vec![2, 7]).unwrap()
+        );
+        assert!(args(&["--size", "0"]).is_err());
+        assert!(args(&["--size", "10"]).is_err());
+        assert!(args(&["--target", "x"]).is_err());
+        assert!(args(&["--start"]).is_err());
+        assert!(args(&["--sise", "10"]).is_err());
+    }
+
+    #[test]
+    fn test_huge_rotations() {
+        assert_eq!(roll_value(50, i64::MAX).unwrap(), (57, 92233720368547758));
+        assert_eq!(roll_value(50, -i64::MAX).unwrap(), (43, 92233720368547758));
+        assert_eq!(roll_value(0, -i64::MAX).unwrap(), (93, 92233720368547758));
+
+        // every step of a one position dial is a click
+        let tiny = Dial::new(1, 0, vec![0]).unwrap();
+        assert_eq!(tiny.roll(0, i64::MAX).unwrap(), (0, i64::MAX));
+        assert_eq!(tiny.roll(0, -i64::MAX).unwrap(), (0, i64::MAX));
+        assert!(tiny.roll(0, i64::MIN).is_err());
+
+        // a long rotation clicks as much as the same distance split in two
+        let dial = Dial::new(97, 13, vec![0, 42, 96]).unwrap();
+        for (a, b) in [
+            (i64::MAX / 2, i64::MAX / 2),
+            (-4_000_000_000_000, -123_456_789),
+            (1 << 40, (1 << 41) + 7),
+        ] {
+            let (cursor, first) = dial.roll(13, a).unwrap();
+            let (end, second) = dial.roll(cursor, b).unwrap();
+            assert_eq!(
+                dial.roll(13, a + b).unwrap(),
+                (end, first + second),
+                "{a} + {b}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_huge_counter() {
+        let tiny = Dial::new(1, 0, vec![0]).unwrap();
+        let mut counter = tiny.counter(NumericMode::Checked);
+        counter.roll(i64::MAX).unwrap();
+        assert!(counter.roll(1).is_err());
+
+        let mut counter = tiny.counter(NumericMode::Big);
+        counter.roll(i64::MAX).unwrap();
+        counter.roll(-i64::MAX).unwrap();
+        assert_eq!(counter.clicks.to_string(), "18446744073709551614");
+        assert_eq!(counter.landed, 2);
+    }
+}
@@ -0,0 +1,226 @@
+use common::args::{Args, Flag};
+use common::numeric::NumericMode;
+use std::io;
+
+use crate::{invalid_data, invalid_input, parse_instruction, Dial, DialCounter};
+
+// a dial written `<name>:<size>[@<start>]`, counting the clicks on 0
+pub fn parse_dial_spec(spec: &str) -> io::Result<(String, Dial)> {
+    let (name, dial) = spec
+        .split_once(':')
+        .ok_or_else(|| invalid_input(format!("expected <name>:<size>[@<start>] in '{spec}'")))?;
+    let name = name.trim();
+    if name.is_empty() || !name.chars().all(char::is_alphanumeric) {
+        return Err(invalid_input(format!("invalid dial name '{name}'")));
+    }
+    let (size, start) = dial.split_once('@').unwrap_or((dial, "0"));
+    let parse = |what: &str, v: &str| {
+        v.trim()
+            .parse::<i32>()
+            .map_err(|err| invalid_input(format!("invalid dial {what} '{v}' in '{spec}': {err}")))
+    };
+    let dial = Dial::new(parse("size", size)?, parse("start", start)?, vec![0])?;
+    Ok((name.to_string(), dial))
+}
+
+// comma separated `<name>:<rotation>` instructions, a blank line gives no instruction
+pub fn parse_lock_line(line: &str, line_number: usize) -> io::Result<Vec<(String, i64)>> {
+    line.split(',')
+        .map(str::trim)
+        .filter(|token| !token.is_empty())
+        .map(|token| {
+            let (name, rotation) = token.split_once(':').ok_or_else(|| {
+                invalid_data(format!(
+                    "line {line_number}: expected <dial>:<rotation> in '{token}'"
+                ))
+            })?;
+            let rotation = parse_instruction(rotation.trim())
+                .map_err(|err| invalid_data(format!("line {line_number}: {err}")))?;
+            Ok((name.trim().to_string(), rotation))
+        })
+        .collect()
+}
+
+// several concentric dials, each with its own counters. with `carry` the dials are coupled
+// like an odometer: every click on 0 of a dial turns the next one by one position
+// in the same direction
+pub struct Lock {
+    pub dials: Vec<(String, DialCounter)>,
+    pub carry: bool,
+}
+
+impl Lock {
+    pub fn new(dials: Vec<(String, Dial)>, carry: bool, mode: NumericMode) -> io::Result<Lock> {
+        if dials.is_empty() {
+            return Err(invalid_input("a lock needs at least one dial".to_string()));
+        }
+        for (idx, (name, _)) in dials.iter().enumerate() {
+            if dials[..idx].iter().any(|(other, _)| other == name) {
+                return Err(invalid_input(format!("dial '{name}' is declared twice")));
+            }
+        }
+        Ok(Lock {
+            dials: dials
+                .into_iter()
+                .map(|(name, dial)| (name, dial.counter(mode)))
+                .collect(),
+            carry,
+        })
+    }
+
+    pub const FLAGS: &'static [Flag] = &[Flag::value("--lock"), Flag::switch("--carry")];
+
+    // flags of the single dial run, a lock has no trace nor stats and its dials are
+    // described by `--lock` alone
+    const SINGLE_DIAL_FLAGS: &'static [&'static str] =
+        &["--trace", "--stats", "--size", "--start", "--target"];
+
+    // `--lock <name>:<size>[@<start>],..` and `--carry`, None without `--lock`
+    pub fn from_args(args: &Args, mode: NumericMode) -> io::Result<Option<Lock>> {
+        let carry = args.has("--carry");
+        let Some(specs) = args.value("--lock") else {
+            return Ok(None);
+        };
+        if let Some(flag) = Lock::SINGLE_DIAL_FLAGS.iter().find(|flag| args.has(flag)) {
+            return Err(invalid_input(format!("--lock cannot be used with {flag}")));
+        }
+        let dials = specs
+            .split(',')
+            .map(parse_dial_spec)
+            .collect::<io::Result<_>>()?;
+        Lock::new(dials, carry, mode).map(Some)
+    }
+
+    pub fn roll(&mut self, name: &str, val: i64) -> io::Result<()> {
+        let mut idx = self
+            .dials
+            .iter()
+            .position(|(other, _)| other == name)
+            .ok_or_else(|| invalid_data(format!("unknown dial '{name}'")))?;
+        let mut val = val;
+        loop {
+            let clicks = self.dials[idx].1.roll(val)?;
+            idx += 1;
+            if !self.carry || clicks == 0 || idx == self.dials.len() {
+                return Ok(());
+            }
+            val = clicks * val.signum();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::stats::StatsFormat;
+    use crate::trace::Trace;
+
+    fn new_lock(specs: &str, carry: bool) -> Lock {
+        let dials = specs
+            .split(',')
+            .map(|spec| parse_dial_spec(spec).unwrap())
+            .collect();
+        Lock::new(dials, carry, NumericMode::Checked).unwrap()
+    }
+
+    fn state(lock: &Lock) -> Vec<(i32, String, String)> {
+        lock.dials
+            .iter()
+            .map(|(_, c)| (c.cursor, c.landed.to_string(), c.clicks.to_string()))
+            .collect()
+    }
+
+    #[test]
+    fn test_parse() {
+        assert_eq!(
+            parse_dial_spec("A:100@50").unwrap(),
+            ("A".to_string(), Dial::default())
+        );
+        assert_eq!(parse_dial_spec("b2:10").unwrap().1.start(), 0);
+        for bad in ["A", ":10", "A:0", "A:10@10", "A-1:10", "A:x"] {
+            assert!(parse_dial_spec(bad).is_err(), "{bad}");
+        }
+        assert_eq!(
+            parse_lock_line("A:L68, b:r12,", 1).unwrap(),
+            vec![("A".to_string(), -68), ("b".to_string(), 12)]
+        );
+        let err = parse_lock_line("A:L68,L5", 4).unwrap_err();
+        assert!(err.to_string().starts_with("line 4: "), "{err}");
+        assert!(parse_lock_line("A:X5", 4).is_err());
+    }
+
+    #[test]
+    fn test_independent_dials() {
+        let mut lock = new_lock("A:100@50,B:10", false);
+        for (name, val) in [("A", -68), ("B", 12), ("A", -30), ("A", 48), ("B", -2)] {
+            lock.roll(name, val).unwrap();
+        }
+        // A follows the day 1 example, B is a 10 positions dial
+        assert_eq!(
+            state(&lock),
+            vec![
+                (0, "1".to_string(), "2".to_string()),
+                (0, "1".to_string(), "2".to_string())
+            ]
+        );
+        assert!(lock.roll("C", 1).is_err());
+    }
+
+    #[test]
+    fn test_carry() {
+        let mut lock = new_lock("A:10,B:10,C:10", true);
+        // A passes 0 twice, turning B by two
+        lock.roll("A", 25).unwrap();
+        assert_eq!(lock.dials[0].1.cursor, 5);
+        assert_eq!(lock.dials[1].1.cursor, 2);
+        assert_eq!(lock.dials[2].1.cursor, 0);
+        // B passes 0 going left, which turns C left by one
+        lock.roll("B", -3).unwrap();
+        assert_eq!(lock.dials[1].1.cursor, 9);
+        assert_eq!(lock.dials[2].1.cursor, 9);
+        // 999 steps on A cascade up to C
+        let mut lock = new_lock("A:10,B:10,C:10", true);
+        lock.roll("A", 999).unwrap();
+        let cursors: Vec<i32> = lock.dials.iter().map(|(_, c)| c.cursor).collect();
+        assert_eq!(cursors, vec![9, 9, 9]);
+
+        let mut uncoupled = new_lock("A:10,B:10", false);
+        uncoupled.roll("A", 25).unwrap();
+        assert_eq!(uncoupled.dials[1].1.cursor, 0);
+    }
+
+    #[test]
+    fn test_lock_args() {
+        let args = |a: &[&str]| {
+            Args::parse(a.iter().map(|s| s.to_string()), Lock::FLAGS)
+                .and_then(|args| Lock::from_args(&args, NumericMode::Checked))
+        };
+        assert!(args(&["--carry"]).unwrap().is_none());
+        let lock = args(&["--lock", "A:100@50,B:10", "--carry"])
+            .unwrap()
+            .unwrap();
+        assert!(lock.carry);
+        assert_eq!(lock.dials.len(), 2);
+        assert!(args(&["--lock=A:10,A:20"]).is_err());
+        assert!(args(&["--lock=A:10", "--cary"]).is_err());
+
+        let known = [Lock::FLAGS, Dial::FLAGS, Trace::FLAGS, StatsFormat::FLAGS].concat();
+        let all = |a: &[&str]| {
+            Args::parse(a.iter().map(|s| s.to_string()), &known)
+                .and_then(|args| Lock::from_args(&args, NumericMode::Checked))
+        };
+        assert!(all(&["--lock=A:10", "--carry"]).unwrap().is_some());
+        assert!(all(&["--trace", "--size=10"]).unwrap().is_none());
+        for extra in [
+            &["--trace"][..],
+            &["--stats=json"],
+            &["--size", "10"],
+            &["--start=3"],
+        ] {
+            let mut a = vec!["--lock=A:10"];
+            a.extend(extra);
+            assert!(all(&a).is_err(), "{extra:?}");
+        }
+        assert!(all(&["--target=5", "--lock=A:10"]).is_err());
+    }
+}
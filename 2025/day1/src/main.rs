@@ -0,0 +1,113 @@
+use aoc2025_day1::lock::{parse_lock_line, Lock};
+use aoc2025_day1::solver::{solve, Goal};
+use aoc2025_day1::stats::{Stats, StatsFormat};
+use aoc2025_day1::trace::{Trace, TraceRow};
+use aoc2025_day1::{format_instruction, parse_line, Dial};
+use common::args::Args;
+use common::numeric::NumericMode;
+use std::env;
+use std::fs::File;
+use std::io::{self, prelude::*, BufReader};
+
+fn main() -> io::Result<()> {
+    let args = Args::from_env(
+        &[
+            NumericMode::FLAGS,
+            Dial::FLAGS,
+            Trace::FLAGS,
+            StatsFormat::FLAGS,
+            Goal::FLAGS,
+            Lock::FLAGS,
+        ]
+        .concat(),
+    )?;
+    let mode = NumericMode::from_args(&args)?;
+    let dial = Dial::from_args(&args)?;
+    let trace = Trace::from_args(&args)?;
+    let stats_format = StatsFormat::from_args(&args)?;
+    if let Some((goal, limits)) = Goal::from_args(&args)? {
+        match solve(&dial, &goal, &limits)? {
+            Some(rotations) => {
+                for rotation in rotations {
+                    println!("{}", format_instruction(rotation));
+                }
+            }
+            None => println!("no rotation sequence reaches the goal"),
+        }
+        return Ok(());
+    }
+    let file =
+        File::open(env::current_dir()?.join("src/input.txt")).expect("failed to read input.txt");
+    let reader = BufReader::new(file);
+
+    if let Some(mut lock) = Lock::from_args(&args, mode)? {
+        for (line_number, line) in reader.lines().enumerate() {
+            for (name, rotation) in parse_lock_line(&line?, line_number + 1)? {
+                lock.roll(&name, rotation).map_err(|err| {
+                    io::Error::new(err.kind(), format!("line {}: {err}", line_number + 1))
+                })?;
+            }
+        }
+        for (name, counter) in &lock.dials {
+            println!(
+                "dial {name}: position {}, landed on zero {}, found counter {}",
+                counter.cursor, counter.landed, counter.clicks
+            );
+        }
+        return Ok(());
+    }
+
+    // stdout holds one output that stays parseable: the json stats, else the trace,
+    // else the report; everything else goes to stderr
+    let json = stats_format == Some(StatsFormat::Json);
+    let print_trace = |line: String| {
+        if json {
+            eprintln!("{line}");
+        } else {
+            println!("{line}");
+        }
+    };
+    if let Some(trace) = &trace {
+        print_trace(trace.header());
+    }
+    let mut counter = dial.counter(mode);
+    let mut stats = Stats::new(dial.size());
+    for (line_number, line) in reader.lines().enumerate() {
+        for rotation in parse_line(&line?, line_number + 1)? {
+            let before = counter.cursor;
+            let clicks = counter.roll(rotation).map_err(|err| {
+                io::Error::new(
+                    io::ErrorKind::InvalidData,
+                    format!("zero counter at line {}: {err}", line_number + 1),
+                )
+            })?;
+            stats.record(line_number + 1, rotation, counter.cursor, clicks);
+            if let Some(trace) = &trace {
+                let row = TraceRow {
+                    line: line_number + 1,
+                    instruction: format_instruction(rotation),
+                    before,
+                    after: counter.cursor,
+                    clicks,
+                };
+                if let Some(row) = trace.row(&row) {
+                    print_trace(row);
+                }
+            }
+        }
+    }
+    let summary = match stats_format {
+        Some(format) => stats.report(format, &counter.landed, &counter.clicks),
+        None => format!(
+            "landed on target: {}\nfound counter: {}",
+            counter.landed, counter.clicks
+        ),
+    };
+    if trace.is_some() && !json {
+        eprintln!("{summary}");
+    } else {
+        println!("{summary}");
+    }
+
+    Ok(())
+}
@@ -0,0 +1,287 @@
+use std::collections::{HashMap, VecDeque};
+use std::io;
+
+use crate::{invalid_input, Dial};
+use common::args::{Args, Flag};
+
+// position and clicks counted so far
+type State = (i32, i64);
+
+// states times rotations tried from each, past this the search would run for minutes and
+// its visited map would not fit in memory
+const MAX_SEARCH: i64 = 20_000_000;
+
+// what the rotations must achieve: a final position, an exact click count, or both
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct Goal {
+    pub position: Option<i32>,
+    pub clicks: Option<i64>,
+}
+
+// magnitude allowed for a single rotation, unbounded when `max` is None
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Limits {
+    pub min: i64,
+    pub max: Option<i64>,
+}
+
+impl Default for Limits {
+    fn default() -> Self {
+        Limits { min: 1, max: None }
+    }
+}
+
+impl Goal {
+    pub const FLAGS: &'static [Flag] = &[
+        Flag::value("--solve-position"),
+        Flag::value("--solve-clicks"),
+        Flag::value("--min-rotation"),
+        Flag::value("--max-rotation"),
+    ];
+
+    // `--solve-position <n>`, `--solve-clicks <n>` with `--min-rotation <n>` and
+    // `--max-rotation <n>`, None without any goal
+    pub fn from_args(args: &Args) -> io::Result<Option<(Goal, Limits)>> {
+        let goal = Goal {
+            position: args.parse_value("--solve-position")?,
+            clicks: args.parse_value("--solve-clicks")?,
+        };
+        let limits = Limits {
+            min: args
+                .parse_value("--min-rotation")?
+                .unwrap_or(Limits::default().min),
+            max: args.parse_value("--max-rotation")?,
+        };
+        if goal == Goal::default() {
+            return Ok(None);
+        }
+        Ok(Some((goal, limits)))
+    }
+
+    fn reached(&self, position: i32, clicks: i64) -> bool {
+        self.position.is_none_or(|p| p == position) && self.clicks.is_none_or(|c| c == clicks)
+    }
+}
+
+// shortest list of rotations turning the dial from its start to the goal, None if unreachable.
+// breadth first search over (position, clicks so far) using `Dial::roll` as the transition,
+// smaller rotations and right before left are tried first
+pub fn solve(dial: &Dial, goal: &Goal, limits: &Limits) -> io::Result<Option<Vec<i64>>> {
+    if let Some(position) = goal.position {
+        if !(0..dial.size()).contains(&position) {
+            return Err(invalid_input(format!(
+                "position {position} is outside of a dial of size {}",
+                dial.size()
+            )));
+        }
+    }
+    let max_clicks = goal.clicks.unwrap_or(0);
+    if max_clicks < 0 {
+        return Err(invalid_input(format!("cannot reach {max_clicks} clicks")));
+    }
+    // beyond this any rotation either loops back to a visited position or overshoots the clicks
+    let bound = (dial.size() as i64)
+        .saturating_mul(max_clicks.saturating_add(1))
+        .saturating_add(limits.min);
+    let max = limits.max.unwrap_or(bound).min(bound);
+    if limits.min < 1 || max < limits.min {
+        return Err(invalid_input(format!(
+            "invalid rotation limits {}..{max}",
+            limits.min
+        )));
+    }
+
+    // clicks are only tracked when they are part of the goal
+    let track = goal.clicks.is_some();
+    let states = (dial.size() as i64).saturating_mul(max_clicks.saturating_add(1));
+    let search = states.saturating_mul(2 * (max - limits.min + 1));
+    if search > MAX_SEARCH {
+        return Err(invalid_input(format!(
+            "searching {states} states with {} rotations each is too long, \
+             lower the clicks or the maximum rotation",
+            2 * (max - limits.min + 1)
+        )));
+    }
+    let start = (dial.start(), 0);
+    if goal.reached(start.0, start.1) {
+        return Ok(Some(Vec::new()));
+    }
+    let mut previous: HashMap<State, (State, i64)> = HashMap::new();
+    let mut queue = VecDeque::from([start]);
+    while let Some((position, clicks)) = queue.pop_front() {
+        for magnitude in limits.min..=max {
+            for val in [magnitude, -magnitude] {
+                let (next, new_clicks) = dial.roll(position, val)?;
+                let total = if track { clicks + new_clicks } else { 0 };
+                if total > max_clicks || (next, total) == start {
+                    continue;
+                }
+                if previous.contains_key(&(next, total)) {
+                    continue;
+                }
+                previous.insert((next, total), ((position, clicks), val));
+                if goal.reached(next, total) {
+                    return Ok(Some(path(&previous, start, (next, total))));
+                }
+                queue.push_back((next, total));
+            }
+        }
+    }
+    Ok(None)
+}
+
+fn path(previous: &HashMap<State, (State, i64)>, start: State, end: State) -> Vec<i64> {
+    let mut rotations = Vec::new();
+    let mut state = end;
+    while state != start {
+        let (prev, val) = previous[&state];
+        rotations.push(val);
+        state = prev;
+    }
+    rotations.reverse();
+    rotations
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::format_instruction;
+
+    // replay the rotations to check they do reach the goal
+    fn replay(dial: &Dial, rotations: &[i64]) -> (i32, i64) {
+        let mut cursor = dial.start();
+        let mut total = 0;
+        for &val in rotations {
+            let clicks;
+            (cursor, clicks) = dial.roll(cursor, val).unwrap();
+            total += clicks;
+        }
+        (cursor, total)
+    }
+
+    fn position(position: i32) -> Goal {
+        Goal {
+            position: Some(position),
+            clicks: None,
+        }
+    }
+
+    #[test]
+    fn test_solve_position() {
+        let dial = Dial::default();
+        assert_eq!(
+            solve(&dial, &position(50), &Limits::default()).unwrap(),
+            Some(vec![])
+        );
+        assert_eq!(
+            solve(&dial, &position(0), &Limits::default()).unwrap(),
+            Some(vec![50])
+        );
+        assert_eq!(
+            solve(&dial, &position(82), &Limits::default()).unwrap(),
+            Some(vec![32])
+        );
+        assert_eq!(
+            solve(&dial, &position(18), &Limits::default()).unwrap(),
+            Some(vec![-32])
+        );
+
+        let limits = Limits {
+            min: 1,
+            max: Some(10),
+        };
+        let rotations = solve(&dial, &position(82), &limits).unwrap().unwrap();
+        assert_eq!(rotations.len(), 4);
+        assert!(rotations.iter().all(|r| r.abs() <= 10));
+        assert_eq!(replay(&dial, &rotations).0, 82);
+    }
+
+    #[test]
+    fn test_solve_clicks() {
+        let dial = Dial::default();
+        let goal = Goal {
+            position: Some(32),
+            clicks: Some(6),
+        };
+        let rotations = solve(&dial, &goal, &Limits::default()).unwrap().unwrap();
+        assert_eq!(rotations.len(), 1);
+        assert_eq!(replay(&dial, &rotations), (32, 6));
+
+        // at most 99 per rotation, each one passes zero at most once
+        let limits = Limits {
+            min: 1,
+            max: Some(99),
+        };
+        let rotations = solve(&dial, &goal, &limits).unwrap().unwrap();
+        assert_eq!(rotations.len(), 6);
+        assert_eq!(replay(&dial, &rotations), (32, 6));
+        let text: Vec<String> = rotations.into_iter().map(format_instruction).collect();
+        assert_eq!(text, ["R51", "L2", "R2", "L2", "R2", "L69"]);
+
+        let goal = Goal {
+            position: None,
+            clicks: Some(2),
+        };
+        let rotations = solve(&dial, &goal, &Limits::default()).unwrap().unwrap();
+        assert_eq!(replay(&dial, &rotations).1, 2);
+    }
+
+    #[test]
+    fn test_solve_unreachable() {
+        // only even rotations on an even dial keep the parity of the position
+        let dial = Dial::new(10, 0, vec![0]).unwrap();
+        let limits = Limits {
+            min: 2,
+            max: Some(2),
+        };
+        assert_eq!(solve(&dial, &position(3), &limits).unwrap(), None);
+        assert!(solve(&dial, &position(10), &limits).is_err());
+        let limits = Limits {
+            min: 3,
+            max: Some(2),
+        };
+        assert!(solve(&dial, &position(3), &limits).is_err());
+    }
+
+    #[test]
+    fn test_solve_too_long() {
+        let dial = Dial::default();
+        let goal = Goal {
+            position: None,
+            clicks: Some(1_000_000_000),
+        };
+        assert!(solve(&dial, &goal, &Limits::default()).is_err());
+        let limits = Limits {
+            min: 1,
+            max: Some(99),
+        };
+        assert!(solve(&dial, &goal, &limits).is_err());
+        // a huge dial with no click goal is too long as well without a rotation bound
+        let huge = Dial::new(1_000_000, 0, vec![0]).unwrap();
+        assert!(solve(&huge, &position(1), &Limits::default()).is_err());
+    }
+
+    #[test]
+    fn test_goal_args() {
+        let args = |a: &[&str]| {
+            Args::parse(a.iter().map(|s| s.to_string()), Goal::FLAGS)
+                .and_then(|args| Goal::from_args(&args))
+        };
+        assert_eq!(args(&[]).unwrap(), None);
+        assert_eq!(
+            args(&["--solve-clicks", "3", "--max-rotation=99"]).unwrap(),
+            Some((
+                Goal {
+                    position: None,
+                    clicks: Some(3)
+                },
+                Limits {
+                    min: 1,
+                    max: Some(99)
+                }
+            ))
+        );
+        assert!(args(&["--solve-position", "x"]).is_err());
+        assert!(args(&["--solve-clicks", "3", "--size", "10"]).is_err());
+    }
+}
@@ -0,0 +1,246 @@
+use common::args::{Args, Flag};
+use common::numeric::Num;
+use std::{collections::BTreeMap, io, str::FromStr};
+
+use crate::{format_instruction, invalid_input};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum StatsFormat {
+    #[default]
+    Text,
+    Json,
+}
+
+impl FromStr for StatsFormat {
+    type Err = io::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "text" => Ok(StatsFormat::Text),
+            "json" => Ok(StatsFormat::Json),
+            _ => Err(invalid_input(format!(
+                "unknown stats format '{s}', expected 'text' or 'json'"
+            ))),
+        }
+    }
+}
+
+impl StatsFormat {
+    pub const FLAGS: &'static [Flag] = &[Flag::optional_value("--stats")];
+
+    // `--stats` prints a text report, `--stats=json` a json one, None without them
+    pub fn from_args(args: &Args) -> io::Result<Option<StatsFormat>> {
+        args.last("--stats")
+            .map(|value| value.map_or(Ok(StatsFormat::Text), str::parse))
+            .transpose()
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MaxClicks {
+    pub line: usize,
+    pub rotation: i64,
+    pub clicks: i64,
+}
+
+// summary of a rotation log, fed one rotation at a time
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Stats {
+    size: i32,
+    pub rotations: u64,
+    // how many rotations ended on each position
+    pub histogram: BTreeMap<i32, u64>,
+    pub full_revolutions: u128,
+    pub longest_run_without_zero: u64,
+    current_run: u64,
+    pub net_displacement: i128,
+    pub max_clicks: Option<MaxClicks>,
+}
+
+impl Stats {
+    pub fn new(size: i32) -> Stats {
+        Stats {
+            size,
+            rotations: 0,
+            histogram: BTreeMap::new(),
+            full_revolutions: 0,
+            longest_run_without_zero: 0,
+            current_run: 0,
+            net_displacement: 0,
+            max_clicks: None,
+        }
+    }
+
+    // `after` and `clicks` are the result of `roll_value` for this rotation
+    pub fn record(&mut self, line: usize, rotation: i64, after: i32, clicks: i64) {
+        self.rotations += 1;
+        *self.histogram.entry(after).or_default() += 1;
+        self.full_revolutions += (rotation.unsigned_abs() / self.size as u64) as u128;
+        self.net_displacement += rotation as i128;
+        if clicks == 0 {
+            self.current_run += 1;
+            self.longest_run_without_zero = self.longest_run_without_zero.max(self.current_run);
+        } else {
+            self.current_run = 0;
+        }
+        if self
+            .max_clicks
+            .as_ref()
+            .is_none_or(|max| clicks > max.clicks)
+        {
+            self.max_clicks = Some(MaxClicks {
+                line,
+                rotation,
+                clicks,
+            });
+        }
+    }
+
+    // the report ends with the puzzle totals, `landed` and `clicks` of the dial counter
+    pub fn report(&self, format: StatsFormat, landed: &Num, clicks: &Num) -> String {
+        match format {
+            StatsFormat::Text => self.text(landed, clicks),
+            StatsFormat::Json => self.json(landed, clicks),
+        }
+    }
+
+    fn text(&self, landed: &Num, clicks: &Num) -> String {
+        let mut lines = vec![
+            format!("rotations: {}", self.rotations),
+            format!("full revolutions: {}", self.full_revolutions),
+            format!(
+                "longest run without zero: {}",
+                self.longest_run_without_zero
+            ),
+            format!("net displacement: {}", self.net_displacement),
+        ];
+        if let Some(max) = &self.max_clicks {
+            lines.push(format!(
+                "most clicks: {} at line {} with {} clicks",
+                format_instruction(max.rotation),
+                max.line,
+                max.clicks
+            ));
+        }
+        lines.push("final positions:".to_string());
+        for (position, count) in &self.histogram {
+            lines.push(format!("{position:>6} {count:>6}"));
+        }
+        lines.push(format!("landed on target: {landed}"));
+        lines.push(format!("found counter: {clicks}"));
+        lines.join("\n")
+    }
+
+    fn json(&self, landed: &Num, clicks: &Num) -> String {
+        let histogram = self
+            .histogram
+            .iter()
+            .map(|(position, count)| format!("\"{position}\":{count}"))
+            .collect::<Vec<_>>()
+            .join(",");
+        let max_clicks = match &self.max_clicks {
+            Some(max) => format!(
+                "{{\"line\":{},\"instruction\":\"{}\",\"clicks\":{}}}",
+                max.line,
+                format_instruction(max.rotation),
+                max.clicks
+            ),
+            None => "null".to_string(),
+        };
+        format!(
+            "{{\"rotations\":{},\"histogram\":{{{histogram}}},\"full_revolutions\":{},\
+             \"longest_run_without_zero\":{},\"net_displacement\":{},\"max_clicks\":{max_clicks},\
+             \"landed\":{landed},\"clicks\":{clicks}}}",
+            self.rotations,
+            self.full_revolutions,
+            self.longest_run_without_zero,
+            self.net_displacement
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::roll_value;
+    use common::numeric::NumericMode;
+
+    fn num(value: i64) -> Num {
+        Num::new(NumericMode::Checked, value)
+    }
+
+    fn example() -> Stats {
+        let mut stats = Stats::new(100);
+        let mut cursor = 50;
+        for (idx, rotation) in [-68, -30, 48, -5, 60, -55, -1, -99, 14, -82, 250]
+            .into_iter()
+            .enumerate()
+        {
+            let clicks;
+            (cursor, clicks) = roll_value(cursor, rotation).unwrap();
+            stats.record(idx + 1, rotation, cursor, clicks);
+        }
+        stats
+    }
+
+    #[test]
+    fn test_stats() {
+        let stats = example();
+        assert_eq!(stats.rotations, 11);
+        assert_eq!(stats.histogram[&0], 3);
+        assert_eq!(stats.histogram[&32], 1);
+        assert_eq!(stats.histogram.values().sum::<u64>(), 11);
+        assert_eq!(stats.full_revolutions, 2);
+        // the example hits zero every other rotation at most
+        assert_eq!(stats.longest_run_without_zero, 1);
+        assert_eq!(stats.net_displacement, 32);
+        assert_eq!(
+            stats.max_clicks,
+            Some(MaxClicks {
+                line: 11,
+                rotation: 250,
+                clicks: 2
+            })
+        );
+    }
+
+    #[test]
+    fn test_reports() {
+        let mut stats = Stats::new(10);
+        assert_eq!(
+            stats.report(StatsFormat::Json, &num(0), &num(0)),
+            "{\"rotations\":0,\"histogram\":{},\"full_revolutions\":0,\
+             \"longest_run_without_zero\":0,\"net_displacement\":0,\"max_clicks\":null,\
+             \"landed\":0,\"clicks\":0}"
+        );
+        stats.record(1, 3, 3, 0);
+        stats.record(2, -25, 8, 3);
+        assert_eq!(
+            stats.report(StatsFormat::Json, &num(0), &num(3)),
+            "{\"rotations\":2,\"histogram\":{\"3\":1,\"8\":1},\"full_revolutions\":2,\
+             \"longest_run_without_zero\":1,\"net_displacement\":-22,\
+             \"max_clicks\":{\"line\":2,\"instruction\":\"L25\",\"clicks\":3},\
+             \"landed\":0,\"clicks\":3}"
+        );
+        assert_eq!(
+            stats.report(StatsFormat::Text, &num(0), &num(3)),
+            "rotations: 2\nfull revolutions: 2\nlongest run without zero: 1\n\
+             net displacement: -22\nmost clicks: L25 at line 2 with 3 clicks\n\
+             final positions:\n     3      1\n     8      1\n\
+             landed on target: 0\nfound counter: 3"
+        );
+    }
+
+    #[test]
+    fn test_stats_args() {
+        let args = |a: &[&str]| {
+            Args::parse(a.iter().map(|s| s.to_string()), StatsFormat::FLAGS)
+                .and_then(|args| StatsFormat::from_args(&args))
+        };
+        assert_eq!(args(&[]).unwrap(), None);
+        assert_eq!(args(&["--stats"]).unwrap(), Some(StatsFormat::Text));
+        assert_eq!(args(&["--stats=json"]).unwrap(), Some(StatsFormat::Json));
+        assert!(args(&["--stats=xml"]).is_err());
+        assert!(args(&["--stats", "json"]).is_err());
+    }
+}
@@ -0,0 +1,142 @@
+use common::args::{Args, Flag};
+use std::{io, str::FromStr};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum TraceFormat {
+    #[default]
+    Table,
+    Csv,
+}
+
+impl FromStr for TraceFormat {
+    type Err = io::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "table" => Ok(TraceFormat::Table),
+            "csv" => Ok(TraceFormat::Csv),
+            _ => Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                format!("unknown trace format '{s}', expected 'table' or 'csv'"),
+            )),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct Trace {
+    pub format: TraceFormat,
+    pub only_clicks: bool,
+}
+
+impl Trace {
+    pub const FLAGS: &'static [Flag] = &[
+        Flag::optional_value("--trace"),
+        Flag::switch("--only-clicks"),
+    ];
+
+    // `--trace` prints a table, `--trace=csv` a csv, `--only-clicks` hides the rotations
+    // without any click
+    pub fn from_args(args: &Args) -> io::Result<Option<Trace>> {
+        let trace = args
+            .last("--trace")
+            .map(|value| value.map_or(Ok(TraceFormat::Table), str::parse))
+            .transpose()?;
+        Ok(trace.map(|format| Trace {
+            format,
+            only_clicks: args.has("--only-clicks"),
+        }))
+    }
+
+    pub fn header(&self) -> String {
+        match self.format {
+            TraceFormat::Table => format!(
+                "{:>6} {:>11} {:>6} {:>6} {:>6}",
+                "line", "instruction", "before", "after", "clicks"
+            ),
+            TraceFormat::Csv => "line,instruction,before,after,clicks".to_string(),
+        }
+    }
+
+    // None when the row is filtered out
+    pub fn row(&self, row: &TraceRow) -> Option<String> {
+        if self.only_clicks && row.clicks == 0 {
+            return None;
+        }
+        Some(match self.format {
+            TraceFormat::Table => format!(
+                "{:>6} {:>11} {:>6} {:>6} {:>6}",
+                row.line, row.instruction, row.before, row.after, row.clicks
+            ),
+            TraceFormat::Csv => format!(
+                "{},{},{},{},{}",
+                row.line, row.instruction, row.before, row.after, row.clicks
+            ),
+        })
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TraceRow {
+    pub line: usize,
+    pub instruction: String,
+    pub before: i32,
+    pub after: i32,
+    pub clicks: i64,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn row(clicks: i64) -> TraceRow {
+        TraceRow {
+            line: 3,
+            instruction: "L68".to_string(),
+            before: 50,
+            after: 82,
+            clicks,
+        }
+    }
+
+    #[test]
+    fn test_trace_args() {
+        let args = |a: &[&str]| {
+            Args::parse(a.iter().map(|s| s.to_string()), Trace::FLAGS)
+                .and_then(|args| Trace::from_args(&args))
+        };
+        assert_eq!(args(&[]).unwrap(), None);
+        assert_eq!(args(&["--only-clicks"]).unwrap(), None);
+        assert_eq!(
+            args(&["--trace", "--only-clicks"]).unwrap(),
+            Some(Trace {
+                format: TraceFormat::Table,
+                only_clicks: true
+            })
+        );
+        assert_eq!(
+            args(&["--trace=csv"]).unwrap().unwrap().format,
+            TraceFormat::Csv
+        );
+        assert!(args(&["--trace=json"]).is_err());
+        assert!(args(&["--only-click"]).is_err());
+    }
+
+    #[test]
+    fn test_trace_rows() {
+        let table = Trace::default();
+        assert_eq!(table.header(), "  line instruction before  after clicks");
+        assert_eq!(
+            table.row(&row(1)).unwrap(),
+            "     3         L68     50     82      1"
+        );
+
+        let csv = Trace {
+            format: TraceFormat::Csv,
+            only_clicks: true,
+        };
+        assert_eq!(csv.header(), "line,instruction,before,after,clicks");
+        assert_eq!(csv.row(&row(2)).unwrap(), "3,L68,50,82,2");
+        assert_eq!(csv.row(&row(0)), None);
+    }
+}
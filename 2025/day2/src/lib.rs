@@ -0,0 +1,879 @@
+pub mod period;
+
+use common::args::{Args, Flag};
+use num_bigint::BigUint;
+use period::periodicity;
+use std::{fmt, io, str::FromStr};
+
+// which repeat counts make an id bad, an id repeated n times is made of n equal chunks
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RepeatRule {
+    Exactly(usize),
+    AtLeast(usize),
+    AnyOf(Vec<usize>),
+}
+
+impl RepeatRule {
+    // part 1: some sequence repeated exactly twice
+    pub const PART1: RepeatRule = RepeatRule::Exactly(2);
+    // part 2: some sequence repeated at least twice
+    pub const PART2: RepeatRule = RepeatRule::AtLeast(2);
+
+    pub const FLAGS: &'static [Flag] = &[Flag::value("--rule")];
+
+    // every `--rule <rule>` (or `--rule=<rule>`), both parts when there is none
+    pub fn from_args(args: &Args) -> io::Result<Vec<RepeatRule>> {
+        let mut rules = args
+            .values("--rule")
+            .into_iter()
+            .map(str::parse)
+            .collect::<io::Result<Vec<_>>>()?;
+        if rules.is_empty() {
+            rules = vec![RepeatRule::PART1, RepeatRule::PART2];
+        }
+        Ok(rules)
+    }
+
+    pub fn accepts(&self, count: usize) -> bool {
+        match self {
+            RepeatRule::Exactly(k) => count == *k,
+            RepeatRule::AtLeast(k) => count >= *k,
+            RepeatRule::AnyOf(counts) => counts.contains(&count),
+        }
+    }
+}
+
+// `2` exactly twice, `2+` at least twice, `2,3` twice or three times
+impl FromStr for RepeatRule {
+    type Err = io::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let parse = |v: &str| match v.trim().parse::<usize>() {
+            Ok(count) if count >= 2 => Ok(count),
+            _ => Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                format!("invalid repeat count '{v}' in rule '{s}', expected an integer >= 2"),
+            )),
+        };
+        if let Some(count) = s.strip_suffix('+') {
+            Ok(RepeatRule::AtLeast(parse(count)?))
+        } else if s.contains(',') {
+            let counts = s.split(',').map(parse).collect::<io::Result<_>>()?;
+            Ok(RepeatRule::AnyOf(counts))
+        } else {
+            Ok(RepeatRule::Exactly(parse(s)?))
+        }
+    }
+}
+
+impl fmt::Display for RepeatRule {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            RepeatRule::Exactly(k) => write!(f, "{k}"),
+            RepeatRule::AtLeast(k) => write!(f, "{k}+"),
+            RepeatRule::AnyOf(counts) => {
+                let counts: Vec<String> = counts.iter().map(usize::to_string).collect();
+                write!(f, "{}", counts.join(","))
+            }
+        }
+    }
+}
+
+// every count n >= 2 such that the id is one chunk repeated n times, in increasing order
+pub fn repeat_counts(id: &str) -> Vec<usize> {
+    let periodicity = periodicity(id);
+    let mut counts: Vec<usize> = periodicity
+        .periods()
+        .into_iter()
+        .map(|period| id.len() / period)
+        .filter(|&n| n >= 2)
+        .collect();
+    counts.reverse();
+    counts
+}
+
+pub fn detect_bad_id_with(id: &str, rule: &RepeatRule) -> bool {
+    repeat_counts(id).into_iter().any(|n| rule.accepts(n))
+}
+
+pub fn detect_bad_id(id: &str) -> bool {
+    detect_bad_id_with(id, &RepeatRule::PART2)
+}
+
+fn invalid_input(msg: String) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidInput, msg)
+}
+
+pub fn check_base(base: u32) -> io::Result<u32> {
+    if (2..=36).contains(&base) {
+        Ok(base)
+    } else {
+        Err(invalid_input(format!(
+            "base must be between 2 and 36, got {base}"
+        )))
+    }
+}
+
+pub const BASE_FLAGS: &[Flag] = &[Flag::value("--base")];
+
+// `--base <n>` or `--base=<n>`, 10 without it
+pub fn base_from_args(args: &Args) -> io::Result<u32> {
+    let Some(value) = args.value("--base") else {
+        return Ok(10);
+    };
+    let base = value
+        .parse()
+        .map_err(|err| invalid_input(format!("invalid base '{value}': {err}")))?;
+    check_base(base)
+}
+
+// an id written in `base`, or in hexadecimal, octal or binary with a 0x, 0o or 0b prefix.
+// a prefix letter which is a digit of `base` is read as a digit: 0b12 is 0xb12 in base 16
+pub fn parse_id(value: &str, base: u32) -> io::Result<u128> {
+    let value = value.trim();
+    let prefix = value
+        .strip_prefix('0')
+        .and_then(|rest| rest.chars().next())
+        .filter(|letter| letter.to_digit(base).is_none());
+    let (digits, radix) = match prefix.map(|letter| letter.to_ascii_lowercase()) {
+        Some('x') => (&value[2..], 16),
+        Some('o') => (&value[2..], 8),
+        Some('b') => (&value[2..], 2),
+        _ => (value, base),
+    };
+    // from_str_radix also takes a leading +
+    if digits.starts_with('+') {
+        return Err(invalid_input(format!("invalid id '{value}'")));
+    }
+    u128::from_str_radix(digits, radix)
+        .map_err(|err| invalid_input(format!("invalid id '{value}' in base {radix}: {err}")))
+}
+
+fn invalid_data(msg: String) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, msg)
+}
+
+// a list of `<begin>-<end>` ranges written in `base`, separated by commas or line breaks.
+// blanks around the ranges and a trailing comma are allowed, errors start with the byte
+// offset of the faulty range
+pub fn parse_ranges(input: &str, base: u32) -> io::Result<Vec<(u128, u128)>> {
+    // every token below is a subslice of input
+    let offset = |token: &str| token.as_ptr() as usize - input.as_ptr() as usize;
+    let mut ranges = Vec::new();
+    let tokens: Vec<&str> = input.split(',').collect();
+    for (idx, token) in tokens.iter().enumerate() {
+        if token.trim().is_empty() {
+            // nothing between two commas, only the last token may be blank
+            if idx + 1 < tokens.len() {
+                return Err(invalid_data(format!("byte {}: empty range", offset(token))));
+            }
+            continue;
+        }
+        for range in token.lines().map(str::trim).filter(|r| !r.is_empty()) {
+            let at = |msg: String| invalid_data(format!("byte {}: {msg}", offset(range)));
+            let (begin, end) = range
+                .split_once('-')
+                .ok_or_else(|| at(format!("expected <begin>-<end>, got '{range}'")))?;
+            let begin = parse_id(begin, base).map_err(|err| at(err.to_string()))?;
+            let end = parse_id(end, base).map_err(|err| at(err.to_string()))?;
+            if begin > end {
+                return Err(at(format!("reversed range '{range}'")));
+            }
+            ranges.push((begin, end));
+        }
+    }
+    Ok(ranges)
+}
+
+// the digits of `id` in `base`, lowercase letters above 9
+pub fn format_id(id: u128, base: u32) -> String {
+    if id == 0 {
+        return "0".to_string();
+    }
+    let mut digits = Vec::new();
+    let mut rest = id;
+    while rest > 0 {
+        digits.push(char::from_digit((rest % base as u128) as u32, base).unwrap());
+        rest /= base as u128;
+    }
+    digits.iter().rev().collect()
+}
+
+// an id of `len` digits whose shortest repeated chunk has `period` digits is repeated
+// n times for every n >= 2 dividing len / period
+fn accepted(rule: &RepeatRule, len: u32, period: u32) -> bool {
+    let primitive_count = (len / period) as usize;
+    (2..=primitive_count).any(|n| primitive_count.is_multiple_of(n) && rule.accepts(n))
+}
+
+fn digits(n: u128, base: u32) -> u32 {
+    n.checked_ilog(base as u128).unwrap_or(0) + 1
+}
+
+// the bounds of the ids of `len` digits inside [num_begin, num_end]
+fn clamp_len(num_begin: u128, num_end: u128, len: u32, base: u32) -> Option<(u128, u128)> {
+    let base = base as u128;
+    let begin = num_begin.max(base.pow(len - 1));
+    let end = match base.checked_pow(len) {
+        Some(limit) => num_end.min(limit - 1),
+        None => num_end,
+    };
+    (begin <= end).then_some((begin, end))
+}
+
+// the `len` digits numbers made of a `period` digits chunk repeated are chunk * multiplier,
+// returns the multiplier and the range of chunks giving a number in [begin, end]
+fn chunks(begin: u128, end: u128, len: u32, period: u32, base: u32) -> (u128, u128, u128) {
+    let shift = (base as u128).pow(period);
+    // 1 + shift + shift^2 .. written this way, (base^len - 1) / (shift - 1) may not fit
+    let multiplier = (0..len / period).fold(0u128, |acc, _| acc * shift + 1);
+    let lo = begin.div_ceil(multiplier).max(shift / base as u128);
+    let hi = (end / multiplier).min(shift - 1);
+    (multiplier, lo, hi)
+}
+
+fn proper_divisors(len: u32) -> Vec<u32> {
+    (1..len).filter(|d| len.is_multiple_of(*d)).collect()
+}
+
+// sum of the bad ids in [num_begin, num_end] without looking at every number: for each length
+// and period, the ids made of a repeated chunk sum to multiplier * (sum of the chunks).
+// an id like 222222 is made of chunks of 1, 2 and 3 digits, so the sum for an exact shortest
+// period is the sum for that period minus the ones of its own divisors
+pub fn sum_repeated_ids(num_begin: u128, num_end: u128, rule: &RepeatRule, base: u32) -> BigUint {
+    let mut total = BigUint::ZERO;
+    if num_begin > num_end {
+        return total;
+    }
+    for len in digits(num_begin, base)..=digits(num_end, base) {
+        let Some((begin, end)) = clamp_len(num_begin, num_end, len, base) else {
+            continue;
+        };
+        let mut exact: Vec<(u32, BigUint)> = Vec::new();
+        for period in proper_divisors(len) {
+            let (multiplier, lo, hi) = chunks(begin, end, len, period, base);
+            let mut sum = if lo <= hi {
+                let (lo, hi) = (BigUint::from(lo), BigUint::from(hi));
+                BigUint::from(multiplier) * ((&lo + &hi) * (hi - lo + 1u32) / 2u32)
+            } else {
+                BigUint::ZERO
+            };
+            for (smaller, smaller_sum) in &exact {
+                if period.is_multiple_of(*smaller) {
+                    sum -= smaller_sum;
+                }
+            }
+            if accepted(rule, len, period) {
+                total += &sum;
+            }
+            exact.push((period, sum));
+        }
+    }
+    total
+}
+
+// every bad id in [num_begin, num_end] in increasing order, generated from the repeated chunks.
+// only chunks which are not repeated themselves are used so each id comes once
+pub fn repeated_ids(num_begin: u128, num_end: u128, rule: &RepeatRule, base: u32) -> Vec<u128> {
+    let mut ids = Vec::new();
+    if num_begin > num_end {
+        return ids;
+    }
+    for len in digits(num_begin, base)..=digits(num_end, base) {
+        let Some((begin, end)) = clamp_len(num_begin, num_end, len, base) else {
+            continue;
+        };
+        let start = ids.len();
+        for period in proper_divisors(len) {
+            if !accepted(rule, len, period) {
+                continue;
+            }
+            let (multiplier, lo, hi) = chunks(begin, end, len, period, base);
+            for chunk in lo..=hi {
+                if !periodicity(&format_id(chunk, base)).is_proper_power() {
+                    ids.push(chunk * multiplier);
+                }
+            }
+        }
+        ids[start..].sort_unstable();
+    }
+    ids
+}
+
+// one sum per rule
+pub fn sum_bad_ids_by_rule(
+    num_begin: u128,
+    num_end: u128,
+    rules: &[RepeatRule],
+    base: u32,
+) -> Vec<BigUint> {
+    rules
+        .iter()
+        .map(|rule| sum_repeated_ids(num_begin, num_end, rule, base))
+        .collect()
+}
+
+// decimal ids repeated at least twice, the puzzle part 2
+pub fn sum_bad_ids(num_begin: u64, num_end: u64) -> u128 {
+    let sum = sum_repeated_ids(num_begin as u128, num_end as u128, &RepeatRule::PART2, 10);
+    // less than 2^32 bad ids below 2^64
+    u128::try_from(sum).expect("bad ids of u64 ranges sum in 128 bits")
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum CountMode {
+    // an id inside several ranges is counted once
+    #[default]
+    Once,
+    // every range is summed on its own
+    PerRange,
+}
+
+impl FromStr for CountMode {
+    type Err = io::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "once" => Ok(CountMode::Once),
+            "per-range" => Ok(CountMode::PerRange),
+            _ => Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                format!("unknown count mode '{s}', expected 'once' or 'per-range'"),
+            )),
+        }
+    }
+}
+
+impl CountMode {
+    pub const FLAGS: &'static [Flag] = &[Flag::value("--count")];
+
+    // `--count <mode>` or `--count=<mode>`, once without it
+    pub fn from_args(args: &Args) -> io::Result<CountMode> {
+        args.value("--count")
+            .map_or(Ok(CountMode::default()), str::parse)
+    }
+}
+
+// every pair of ranges sharing at least one id, in input order
+pub fn overlapping_ranges(ranges: &[(u128, u128)]) -> Vec<((u128, u128), (u128, u128))> {
+    let mut order: Vec<usize> = (0..ranges.len()).collect();
+    order.sort_by_key(|&idx| ranges[idx]);
+    let mut pairs = Vec::new();
+    for (pos, &i) in order.iter().enumerate() {
+        for &j in &order[pos + 1..] {
+            if ranges[j].0 > ranges[i].1 {
+                break;
+            }
+            pairs.push((i.min(j), i.max(j)));
+        }
+    }
+    pairs.sort_unstable();
+    pairs
+        .into_iter()
+        .map(|(i, j)| (ranges[i], ranges[j]))
+        .collect()
+}
+
+// disjoint and sorted ranges covering the same ids, touching ranges are joined too
+pub fn merge_ranges(ranges: &[(u128, u128)]) -> Vec<(u128, u128)> {
+    let mut sorted: Vec<(u128, u128)> = ranges.iter().copied().filter(|r| r.0 <= r.1).collect();
+    sorted.sort_unstable();
+    let mut merged: Vec<(u128, u128)> = Vec::new();
+    for (begin, end) in sorted {
+        match merged.last_mut() {
+            Some(last) if begin <= last.1.saturating_add(1) => last.1 = last.1.max(end),
+            _ => merged.push((begin, end)),
+        }
+    }
+    merged
+}
+
+// one sum per rule over all the ranges
+pub fn sum_ranges(
+    ranges: &[(u128, u128)],
+    rules: &[RepeatRule],
+    mode: CountMode,
+    base: u32,
+) -> Vec<BigUint> {
+    let merged;
+    let ranges = match mode {
+        CountMode::Once => {
+            merged = merge_ranges(ranges);
+            &merged
+        }
+        CountMode::PerRange => ranges,
+    };
+    let mut sums = vec![BigUint::ZERO; rules.len()];
+    for &(begin, end) in ranges {
+        let range_sums = sum_bad_ids_by_rule(begin, end, rules, base);
+        for (sum, range_sum) in sums.iter_mut().zip(range_sums) {
+            *sum += range_sum;
+        }
+    }
+    sums
+}
+
+// a bad id with the chunk a rule accepted, e.g. 824824824 = "824" x3
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BadId {
+    pub id: u128,
+    // the id and its chunk are written in the base of the search
+    pub digits: String,
+    pub unit: String,
+    pub repeats: usize,
+}
+
+impl fmt::Display for BadId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} = \"{}\" x{}", self.digits, self.unit, self.repeats)
+    }
+}
+
+// the ids of [num_begin, num_end] bad for any of the rules, in increasing order. each id
+// comes with the largest repeat count a rule accepts, so its shortest accepted chunk:
+// 222222 is "2" x6 for 2+ but "222" x2 for 2
+pub fn bad_ids_detail(
+    num_begin: u128,
+    num_end: u128,
+    rules: &[RepeatRule],
+    base: u32,
+) -> Vec<BadId> {
+    let mut ids: Vec<u128> = rules
+        .iter()
+        .flat_map(|rule| repeated_ids(num_begin, num_end, rule, base))
+        .collect();
+    ids.sort_unstable();
+    ids.dedup();
+    ids.into_iter()
+        .map(|id| {
+            let digits = format_id(id, base);
+            let repeats = repeat_counts(&digits)
+                .into_iter()
+                .filter(|&n| rules.iter().any(|rule| rule.accepts(n)))
+                .max()
+                .expect("a listed id is accepted by a rule");
+            BadId {
+                id,
+                unit: digits[..digits.len() / repeats].to_string(),
+                digits,
+                repeats,
+            }
+        })
+        .collect()
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ReportFormat {
+    #[default]
+    Text,
+    Csv,
+}
+
+impl FromStr for ReportFormat {
+    type Err = io::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "text" => Ok(ReportFormat::Text),
+            "csv" => Ok(ReportFormat::Csv),
+            _ => Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                format!("unknown report format '{s}', expected 'text' or 'csv'"),
+            )),
+        }
+    }
+}
+
+impl ReportFormat {
+    pub const FLAGS: &'static [Flag] = &[Flag::optional_value("--report")];
+
+    // `--report` lists the bad ids as text, `--report=csv` as csv, None without them
+    pub fn from_args(args: &Args) -> io::Result<Option<ReportFormat>> {
+        args.last("--report")
+            .map(|value| value.map_or(Ok(ReportFormat::Text), str::parse))
+            .transpose()
+    }
+}
+
+// every bad id grouped by input range, with the count and sum of each range. the csv has
+// one row per id then one row per range with only the count and sum filled.
+// ids are written in `base`, the sums in decimal
+pub fn report(
+    ranges: &[(u128, u128)],
+    rules: &[RepeatRule],
+    format: ReportFormat,
+    base: u32,
+) -> String {
+    let mut lines = Vec::new();
+    if format == ReportFormat::Csv {
+        lines.push("range,id,unit,repeats,count,sum".to_string());
+    }
+    for &(begin, end) in ranges {
+        let ids = bad_ids_detail(begin, end, rules, base);
+        let range = format!("{}-{}", format_id(begin, base), format_id(end, base));
+        let sum: BigUint = ids.iter().map(|bad| BigUint::from(bad.id)).sum();
+        match format {
+            ReportFormat::Text => {
+                lines.push(format!("{range}: {} bad ids, sum {sum}", ids.len()));
+                lines.extend(ids.iter().map(|bad| format!("  {bad}")));
+            }
+            ReportFormat::Csv => {
+                lines.extend(
+                    ids.iter().map(|bad| {
+                        format!("{range},{},{},{},,", bad.digits, bad.unit, bad.repeats)
+                    }),
+                );
+                lines.push(format!("{range},,,,{},{sum}", ids.len()));
+            }
+        }
+    }
+    lines.join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn bigs(sums: &[u128]) -> Vec<BigUint> {
+        sums.iter().map(|&sum| BigUint::from(sum)).collect()
+    }
+
+    fn parse_args(a: &[&str], flags: &[Flag]) -> io::Result<Args> {
+        Args::parse(a.iter().map(|s| s.to_string()), flags)
+    }
+
+    #[test]
+    fn test_detect_bad_id() {
+        assert!(detect_bad_id("11"));
+        assert!(detect_bad_id("22"));
+        assert!(detect_bad_id("99"));
+        assert!(detect_bad_id("1010"));
+        assert!(detect_bad_id("1188511885"));
+        assert!(detect_bad_id("222222"));
+        assert!(detect_bad_id("446446"));
+        assert!(detect_bad_id("38593859"));
+        assert!(detect_bad_id("111"));
+        assert!(detect_bad_id("999"));
+        assert!(detect_bad_id("565656"));
+        assert!(detect_bad_id("824824824"));
+        assert!(detect_bad_id("2121212121"));
+        assert!(!detect_bad_id("12"));
+        assert!(!detect_bad_id("112"));
+        assert!(!detect_bad_id("1234567890"));
+        assert!(!detect_bad_id("222220"));
+        assert!(!detect_bad_id("222221"));
+        assert!(!detect_bad_id("222223"));
+        assert!(!detect_bad_id("222224"));
+    }
+
+    #[test]
+    fn test_sum_bad_ids() {
+        assert_eq!(sum_bad_ids(11, 22), 33);
+        assert_eq!(sum_bad_ids(95, 115), 210);
+        assert_eq!(sum_bad_ids(1698522, 1698528), 0);
+    }
+
+    #[test]
+    fn test_repeat_rules() {
+        assert_eq!(repeat_counts("1111"), vec![2, 4]);
+        assert_eq!(repeat_counts("824824824"), vec![3]);
+        assert_eq!(repeat_counts("7"), Vec::<usize>::new());
+
+        let part1 = RepeatRule::PART1;
+        assert!(detect_bad_id_with("1111", &part1));
+        assert!(detect_bad_id_with("446446", &part1));
+        assert!(!detect_bad_id_with("111", &part1));
+        assert!(!detect_bad_id_with("824824824", &part1));
+
+        let odd: RepeatRule = "3,5".parse().unwrap();
+        assert!(detect_bad_id_with("824824824", &odd));
+        assert!(detect_bad_id_with("11111", &odd));
+        assert!(!detect_bad_id_with("1010", &odd));
+        assert!(detect_bad_id_with("1111", &"4+".parse().unwrap()));
+        assert!(!detect_bad_id_with("1010", &"3+".parse().unwrap()));
+    }
+
+    #[test]
+    fn test_parse_rule() {
+        assert_eq!("2".parse::<RepeatRule>().unwrap(), RepeatRule::PART1);
+        assert_eq!("2+".parse::<RepeatRule>().unwrap(), RepeatRule::PART2);
+        let rule: RepeatRule = "2,3,5".parse().unwrap();
+        assert_eq!(rule, RepeatRule::AnyOf(vec![2, 3, 5]));
+        assert_eq!(rule.to_string(), "2,3,5");
+        assert_eq!(RepeatRule::PART2.to_string(), "2+");
+        for bad in ["", "1", "+", "x", "2,,3", "1+"] {
+            assert!(bad.parse::<RepeatRule>().is_err(), "{bad}");
+        }
+
+        let args = |a: &[&str]| {
+            parse_args(a, RepeatRule::FLAGS).and_then(|args| RepeatRule::from_args(&args))
+        };
+        assert_eq!(
+            args(&[]).unwrap(),
+            vec![RepeatRule::PART1, RepeatRule::PART2]
+        );
+        assert_eq!(
+            args(&["--rule", "3", "--rule=2,4"]).unwrap(),
+            vec![RepeatRule::Exactly(3), RepeatRule::AnyOf(vec![2, 4])]
+        );
+        assert!(args(&["--rule"]).is_err());
+        assert!(args(&["--rules", "3"]).is_err());
+    }
+
+    #[test]
+    fn test_sum_by_rule() {
+        let rules = [RepeatRule::PART1, RepeatRule::PART2];
+        assert_eq!(sum_bad_ids_by_rule(11, 22, &rules, 10), bigs(&[33, 33]));
+        assert_eq!(sum_bad_ids_by_rule(95, 115, &rules, 10), bigs(&[99, 210]));
+        assert_eq!(
+            sum_bad_ids_by_rule(998, 1012, &rules, 10),
+            bigs(&[1010, 2009])
+        );
+        assert_eq!(sum_bad_ids_by_rule(1, 2, &[], 10), bigs(&[]));
+    }
+
+    // xorshift, enough to pick test ranges without a dependency
+    fn next_random(state: &mut u64) -> u64 {
+        *state ^= *state << 13;
+        *state ^= *state >> 7;
+        *state ^= *state << 17;
+        *state
+    }
+
+    #[test]
+    fn test_enumeration_matches_scan() {
+        let rules: Vec<RepeatRule> = ["2", "2+", "3", "2,3", "4+"]
+            .iter()
+            .map(|r| r.parse().unwrap())
+            .collect();
+        let mut state = 0x2025_0002;
+        // narrow ranges keep the scan fast, the random starts still cross digit lengths
+        for _ in 0..50 {
+            let base = [10, 10, 2, 3, 16, 36][(state % 6) as usize];
+            let begin = (next_random(&mut state) % 10u64.pow(1 + (state % 7) as u32)) as u128;
+            let end = begin + (next_random(&mut state) % 2_000) as u128;
+            let ids: Vec<(u128, String)> = (begin..=end).map(|n| (n, format_id(n, base))).collect();
+            for rule in &rules {
+                let expected: Vec<u128> = ids
+                    .iter()
+                    .filter(|(_, digits)| detect_bad_id_with(digits, rule))
+                    .map(|&(n, _)| n)
+                    .collect();
+                assert_eq!(
+                    repeated_ids(begin, end, rule, base),
+                    expected,
+                    "{begin}-{end} {rule} base {base}"
+                );
+                assert_eq!(
+                    sum_repeated_ids(begin, end, rule, base),
+                    expected.into_iter().map(BigUint::from).sum::<BigUint>(),
+                    "{begin}-{end} {rule} base {base}"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_huge_ranges() {
+        // 11, 22, .., 99 then 1010, 1111, .. 9999: the chunks sum times the multiplier
+        assert_eq!(
+            sum_repeated_ids(1, 9999, &RepeatRule::PART1, 10),
+            BigUint::from(11u32 * 45 + 101 * 4905)
+        );
+        assert_eq!(
+            sum_repeated_ids(5, 4, &RepeatRule::PART2, 10),
+            BigUint::default()
+        );
+        assert!(repeated_ids(10, 1, &RepeatRule::PART2, 10).is_empty());
+        // the whole u128 range, the sum does not fit in it
+        let all = sum_repeated_ids(0, u128::MAX, &RepeatRule::PART2, 10);
+        assert!(all > BigUint::from(u128::MAX));
+        let ids = repeated_ids(u128::MAX - 1000, u128::MAX, &RepeatRule::PART2, 10);
+        assert_eq!(ids, Vec::<u128>::new());
+        // in base 16 the top of the range is all `f`, a repetition of `f`
+        let ids = repeated_ids(u128::MAX - 1000, u128::MAX, &RepeatRule::PART2, 16);
+        assert_eq!(ids, vec![u128::MAX]);
+        let ids = repeated_ids(1_000_000_000, 1_000_200_000, &RepeatRule::PART1, 10);
+        assert_eq!(ids, vec![1_000_010_000, 1_000_110_001]);
+    }
+
+    #[test]
+    fn test_overlaps() {
+        let ranges = [
+            (95, 115),
+            (11, 22),
+            (100, 120),
+            (20, 30),
+            (31, 40),
+            (110, 111),
+        ];
+        assert_eq!(
+            overlapping_ranges(&ranges),
+            vec![
+                ((95, 115), (100, 120)),
+                ((95, 115), (110, 111)),
+                ((11, 22), (20, 30)),
+                ((100, 120), (110, 111)),
+            ]
+        );
+        assert_eq!(merge_ranges(&ranges), vec![(11, 40), (95, 120)]);
+        assert_eq!(
+            merge_ranges(&[(5, 4), (0, u128::MAX)]),
+            vec![(0, u128::MAX)]
+        );
+        assert!(overlapping_ranges(&[(1, 2), (3, 4)]).is_empty());
+    }
+
+    #[test]
+    fn test_bases() {
+        assert_eq!(parse_id("ff", 16).unwrap(), 255);
+        assert_eq!(parse_id("0xFF", 10).unwrap(), 255);
+        assert_eq!(parse_id("0b1010", 10).unwrap(), 10);
+        assert_eq!(parse_id("0o17", 10).unwrap(), 15);
+        assert_eq!(parse_id("z", 36).unwrap(), 35);
+        // b is a digit in base 16, x is not
+        assert_eq!(parse_id("0b12", 16).unwrap(), 0xb12);
+        assert_eq!(parse_id("0B12", 16).unwrap(), 0xb12);
+        assert_eq!(parse_id("0x12", 16).unwrap(), 0x12);
+        assert_eq!(parse_id("0o17", 16).unwrap(), 15);
+        assert_eq!(parse_id("0x1", 36).unwrap(), 33 * 36 + 1);
+        assert_eq!(
+            parse_id("340282366920938463463374607431768211455", 10).unwrap(),
+            u128::MAX
+        );
+        for bad in [
+            "",
+            "+5",
+            "12a",
+            "0x",
+            "0b2",
+            "340282366920938463463374607431768211456",
+        ] {
+            assert!(parse_id(bad, 10).is_err(), "{bad}");
+        }
+        assert_eq!(format_id(255, 16), "ff");
+        assert_eq!(format_id(10, 2), "1010");
+        assert_eq!(format_id(0, 7), "0");
+        assert_eq!(format_id(u128::MAX, 16), "f".repeat(32));
+
+        // 0xaa, 0xbb and 0xabab are repeated in base 16, not in base 10
+        assert!(detect_bad_id(&format_id(0xabab, 16)));
+        assert_eq!(
+            repeated_ids(0xa0, 0xff, &RepeatRule::PART1, 16),
+            [0xaa, 0xbb, 0xcc, 0xdd, 0xee, 0xff]
+        );
+        // 3, 7, 10 and 15 are 11, 111, 1010 and 1111 in binary
+        assert_eq!(repeated_ids(1, 15, &RepeatRule::PART2, 2), [3, 7, 10, 15]);
+        assert_eq!(
+            sum_bad_ids_by_rule(1, 15, &[RepeatRule::PART1], 2),
+            bigs(&[3 + 10 + 15])
+        );
+
+        let args = |a: &[&str]| parse_args(a, BASE_FLAGS).and_then(|args| base_from_args(&args));
+        assert_eq!(args(&[]).unwrap(), 10);
+        assert_eq!(args(&["--base", "16"]).unwrap(), 16);
+        assert_eq!(args(&["--base=36"]).unwrap(), 36);
+        for bad in [["--base", "1"], ["--base", "37"], ["--base", "x"]] {
+            assert!(args(&bad).is_err());
+        }
+        assert!(args(&["--base"]).is_err());
+    }
+
+    #[test]
+    fn test_parse_ranges() {
+        assert_eq!(
+            parse_ranges("11-22,95-115,\n998-1012,\n", 10).unwrap(),
+            vec![(11, 22), (95, 115), (998, 1012)]
+        );
+        assert_eq!(
+            parse_ranges("11-22\r\n 0x10-0x1f ", 10).unwrap(),
+            vec![(11, 22), (16, 31)]
+        );
+        assert_eq!(parse_ranges("a-ff", 16).unwrap(), vec![(10, 255)]);
+        assert_eq!(parse_ranges(" \n", 10).unwrap(), vec![]);
+        assert_eq!(parse_ranges("7-7", 10).unwrap(), vec![(7, 7)]);
+
+        let err = |input: &str| parse_ranges(input, 10).unwrap_err().to_string();
+        assert_eq!(err("11-22,,30-40"), "byte 6: empty range");
+        assert_eq!(err("11-22, 40-30"), "byte 7: reversed range '40-30'");
+        assert_eq!(
+            err("11-22,\n33"),
+            "byte 7: expected <begin>-<end>, got '33'"
+        );
+        assert!(err("11-22,5-x").starts_with("byte 6: invalid id 'x'"));
+        assert!(err("-5").starts_with("byte 0: invalid id ''"));
+    }
+
+    #[test]
+    fn test_count_modes() {
+        let rules = [RepeatRule::PART2];
+        let ranges = [(11, 22), (20, 33), (11, 22)];
+        assert_eq!(
+            sum_ranges(&ranges, &rules, CountMode::Once, 10),
+            bigs(&[66])
+        );
+        assert_eq!(
+            sum_ranges(&ranges, &rules, CountMode::PerRange, 10),
+            bigs(&[33 + 55 + 33])
+        );
+
+        let args = |a: &[&str]| {
+            parse_args(a, CountMode::FLAGS).and_then(|args| CountMode::from_args(&args))
+        };
+        assert_eq!(args(&[]).unwrap(), CountMode::Once);
+        assert_eq!(
+            args(&["--count", "per-range"]).unwrap(),
+            CountMode::PerRange
+        );
+        assert!(args(&["--count=twice"]).is_err());
+    }
+
+    #[test]
+    fn test_report() {
+        let rules = [RepeatRule::PART1, RepeatRule::PART2];
+        let ids = bad_ids_detail(824824821, 824824827, &rules, 10);
+        assert_eq!(ids.len(), 1);
+        assert_eq!(ids[0].to_string(), "824824824 = \"824\" x3");
+        assert_eq!(
+            bad_ids_detail(222220, 222224, &rules, 10)[0].to_string(),
+            "222222 = \"2\" x6"
+        );
+        // 1010 to 2424, 1111 and 2222 being also "1" x4 and "2" x4 are listed once
+        assert_eq!(bad_ids_detail(1000, 2500, &rules, 10).len(), 15);
+        // the chunk comes from a count the rules accept, not from the shortest period
+        let part1 = [RepeatRule::PART1];
+        assert_eq!(
+            bad_ids_detail(222222, 222222, &part1, 10)[0].to_string(),
+            "222222 = \"222\" x2"
+        );
+        assert_eq!(
+            bad_ids_detail(1111, 1111, &part1, 10)[0].to_string(),
+            "1111 = \"11\" x2"
+        );
+        let odd = ["3,5".parse().unwrap()];
+        assert_eq!(
+            bad_ids_detail(111111, 111111, &odd, 10)[0].to_string(),
+            "111111 = \"11\" x3"
+        );
+
+        let ranges = [(11, 22), (1698522, 1698528)];
+        assert_eq!(
+            report(&ranges, &rules, ReportFormat::Text, 10),
+            "11-22: 2 bad ids, sum 33\n  11 = \"1\" x2\n  22 = \"2\" x2\n\
+             1698522-1698528: 0 bad ids, sum 0"
+        );
+        assert_eq!(
+            report(&ranges, &rules, ReportFormat::Csv, 10),
+            "range,id,unit,repeats,count,sum\n11-22,11,1,2,,\n11-22,22,2,2,,\n11-22,,,,2,33\n\
+             1698522-1698528,,,,0,0"
+        );
+
+        let args = |a: &[&str]| {
+            parse_args(a, ReportFormat::FLAGS).and_then(|args| ReportFormat::from_args(&args))
+        };
+        assert_eq!(args(&[]).unwrap(), None);
+        assert_eq!(args(&["--report=csv"]).unwrap(), Some(ReportFormat::Csv));
+        assert!(args(&["--report=json"]).is_err());
+        assert!(args(&["--rule", "2"]).is_err());
+    }
+}
@@ -0,0 +1,55 @@
+use aoc2025_day2::{
+    BASE_FLAGS, CountMode, RepeatRule, ReportFormat, base_from_args, format_id, overlapping_ranges,
+    parse_ranges, report, sum_ranges,
+};
+use common::args::Args;
+use std::env;
+use std::fs::File;
+use std::io::{self, BufReader, Read};
+
+fn main() -> io::Result<()> {
+    let args = Args::from_env(
+        &[
+            RepeatRule::FLAGS,
+            CountMode::FLAGS,
+            ReportFormat::FLAGS,
+            BASE_FLAGS,
+        ]
+        .concat(),
+    )?;
+    let rules = RepeatRule::from_args(&args)?;
+    let count_mode = CountMode::from_args(&args)?;
+    let report_format = ReportFormat::from_args(&args)?;
+    let base = base_from_args(&args)?;
+    let file =
+        File::open(env::current_dir()?.join("src/input.txt")).expect("failed to read input.txt");
+    let mut reader = BufReader::new(file);
+
+    let mut ids = String::new();
+    reader.read_to_string(&mut ids)?;
+    let ranges = parse_ranges(&ids, base)?;
+    for ((lb, le), (rb, re)) in overlapping_ranges(&ranges) {
+        eprintln!(
+            "warning: ranges {}-{} and {}-{} overlap",
+            format_id(lb, base),
+            format_id(le, base),
+            format_id(rb, base),
+            format_id(re, base)
+        );
+    }
+
+    if let Some(format) = report_format {
+        println!("{}", report(&ranges, &rules, format, base));
+    }
+    let sums = sum_ranges(&ranges, &rules, count_mode, base);
+    for (rule, sum) in rules.iter().zip(sums) {
+        // stdout holds the report alone, a csv report stays valid
+        if report_format.is_some() {
+            eprintln!("sum of bad ids repeated {rule} times: {sum}");
+        } else {
+            println!("sum of bad ids repeated {rule} times: {sum}");
+        }
+    }
+
+    Ok(())
+}
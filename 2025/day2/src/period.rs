@@ -0,0 +1,142 @@
+// periodicity of a word, computed on its bytes: ids are ascii digits
+
+// prefix[i] is the length of the longest proper border (both a prefix and a suffix)
+// of word[..=i], the KMP failure function
+pub fn prefix_function(word: &[u8]) -> Vec<usize> {
+    let mut prefix = vec![0; word.len()];
+    for i in 1..word.len() {
+        let mut k = prefix[i - 1];
+        while k > 0 && word[i] != word[k] {
+            k = prefix[k - 1];
+        }
+        if word[i] == word[k] {
+            k += 1;
+        }
+        prefix[i] = k;
+    }
+    prefix
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Periodicity {
+    pub len: usize,
+    // smallest p with word[i] == word[i + p] everywhere, it need not divide len
+    pub minimal_period: usize,
+    // length of the primitive word the word is a power of
+    pub root: usize,
+}
+
+impl Periodicity {
+    // the word is its root repeated this many times
+    pub fn exponent(&self) -> usize {
+        if self.len == 0 {
+            0
+        } else {
+            self.len / self.root
+        }
+    }
+
+    // a primitive word repeated at least twice
+    pub fn is_proper_power(&self) -> bool {
+        self.exponent() >= 2
+    }
+
+    // every period dividing the length, in increasing order: the multiples of the root
+    pub fn periods(&self) -> Vec<usize> {
+        (1..=self.exponent())
+            .filter(|k| self.exponent().is_multiple_of(*k))
+            .map(|k| k * self.root)
+            .collect()
+    }
+}
+
+// linear in the word length. a period dividing the length is a multiple of the minimal
+// period (Fine and Wilf), so the root is the minimal period when it divides the length
+// and the whole word otherwise
+pub fn periodicity(word: &str) -> Periodicity {
+    let len = word.len();
+    let minimal_period = len - prefix_function(word.as_bytes()).last().unwrap_or(&0);
+    let root = if minimal_period > 0 && len.is_multiple_of(minimal_period) {
+        minimal_period
+    } else {
+        len
+    };
+    Periodicity {
+        len,
+        minimal_period,
+        root,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // word[i] == word[i + p] for every i, straight from the definition
+    fn is_period(word: &[u8], p: usize) -> bool {
+        (0..word.len().saturating_sub(p)).all(|i| word[i] == word[i + p])
+    }
+
+    #[test]
+    fn test_prefix_function() {
+        assert_eq!(prefix_function(b"abcabcd"), [0, 0, 0, 1, 2, 3, 0]);
+        assert_eq!(prefix_function(b"aabaaab"), [0, 1, 0, 1, 2, 2, 3]);
+        assert_eq!(prefix_function(b""), Vec::<usize>::new());
+    }
+
+    #[test]
+    fn test_periodicity() {
+        let p = periodicity("824824824");
+        assert_eq!((p.minimal_period, p.root, p.exponent()), (3, 3, 3));
+        assert_eq!(p.periods(), [3, 9]);
+        assert!(p.is_proper_power());
+
+        let p = periodicity("111111");
+        assert_eq!(p.periods(), [1, 2, 3, 6]);
+        assert_eq!(p.exponent(), 6);
+
+        // 12121 has period 2 but is not a power of 12
+        let p = periodicity("12121");
+        assert_eq!((p.minimal_period, p.root), (2, 5));
+        assert_eq!(p.periods(), [5]);
+        assert!(!p.is_proper_power());
+
+        let p = periodicity("7");
+        assert_eq!((p.minimal_period, p.root, p.exponent()), (1, 1, 1));
+        assert!(!p.is_proper_power());
+        let p = periodicity("");
+        assert_eq!((p.minimal_period, p.exponent()), (0, 0));
+        assert!(p.periods().is_empty());
+    }
+
+    #[test]
+    fn test_against_definition() {
+        // every word of up to 8 letters over {a, b}
+        for len in 1..=8 {
+            for bits in 0..1u32 << len {
+                let word: String = (0..len)
+                    .map(|i| if bits >> i & 1 == 1 { 'b' } else { 'a' })
+                    .collect();
+                let bytes = word.as_bytes();
+                let p = periodicity(&word);
+                let minimal = (1..=len).find(|&q| is_period(bytes, q)).unwrap();
+                assert_eq!(p.minimal_period, minimal, "{word}");
+                let periods: Vec<usize> = (1..=len)
+                    .filter(|&q| len.is_multiple_of(q) && is_period(bytes, q))
+                    .collect();
+                assert_eq!(p.periods(), periods, "{word}");
+            }
+        }
+    }
+
+    #[test]
+    fn test_long_word() {
+        // a quadratic split and compare would take a while here
+        let word = "0123456789".repeat(200_000) + "0";
+        let p = periodicity(&word);
+        assert_eq!((p.minimal_period, p.root), (10, word.len()));
+        let p = periodicity(&word[..word.len() - 1]);
+        assert_eq!(p.exponent(), 200_000);
+        assert_eq!(p.periods().len(), 42);
+    }
+}
@@ -0,0 +1,41 @@
+// compares the linear stack selection with the window scan on long banks,
+// run with `cargo bench`
+use aoc2025_day3::{Selection, select_batteries, select_batteries_scan};
+use std::hint::black_box;
+use std::io;
+use std::time::{Duration, Instant};
+
+const BANK_LEN: usize = 300_000;
+
+fn bank(len: usize) -> String {
+    let mut state: u64 = 0x2545_f491_4f6c_dd1d;
+    (0..len)
+        .map(|_| {
+            state ^= state << 13;
+            state ^= state >> 7;
+            state ^= state << 17;
+            char::from(b'1' + (state % 9) as u8)
+        })
+        .collect()
+}
+
+fn time(select: fn(&str, usize) -> io::Result<Selection>, bank: &str, size: usize) -> Duration {
+    let start = Instant::now();
+    black_box(select(black_box(bank), size).unwrap());
+    start.elapsed()
+}
+
+fn main() {
+    let bank = bank(BANK_LEN);
+    println!("{:>8} {:>12} {:>12}", "picks", "stack", "scan");
+    for size in [12, 1_000, 5_000, 150_000, BANK_LEN - 12] {
+        let stack = time(select_batteries, &bank, size);
+        // the scan goes over size * (len - size) digits, too slow past a few thousands picks
+        let scan = if size * (BANK_LEN - size) <= 2_000_000_000 {
+            format!("{:.2?}", time(select_batteries_scan, &bank, size))
+        } else {
+            "-".to_string()
+        };
+        println!("{size:>8} {:>12} {scan:>12}", format!("{stack:.2?}"));
+    }
+}
@@ -0,0 +1,636 @@
+use common::args::{Args, Flag};
+use num_bigint::BigUint;
+use std::{io, str::FromStr};
+
+// the puzzle picks 2 batteries per bank for part 1 and 12 for part 2
+pub const DEFAULT_PICKS: [usize; 2] = [2, 12];
+
+fn invalid_input(msg: String) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidInput, msg)
+}
+
+fn invalid_data(msg: String) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, msg)
+}
+
+pub const PICK_FLAGS: &[Flag] = &[Flag::value("--pick")];
+
+// every `--pick <n>,..` (or `--pick=<n>,..`) in order, both parts when there is none
+pub fn picks_from_args(args: &Args) -> io::Result<Vec<usize>> {
+    let mut picks = Vec::new();
+    for value in args.values("--pick") {
+        for count in value.split(',') {
+            match count.trim().parse::<usize>() {
+                Ok(count) if count > 0 => picks.push(count),
+                _ => {
+                    return Err(invalid_input(format!(
+                        "invalid battery count '{count}' in '{value}', expected an integer > 0"
+                    )));
+                }
+            }
+        }
+    }
+    if picks.is_empty() {
+        picks = DEFAULT_PICKS.to_vec();
+    }
+    Ok(picks)
+}
+
+// the batteries picked in a bank, `indices` are increasing byte offsets into the bank
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Selection {
+    pub value: BigUint,
+    pub indices: Vec<usize>,
+}
+
+// at least one battery to pick, a bank of digits long enough for them
+fn check_bank(input: &str, size: usize) -> io::Result<()> {
+    if size == 0 {
+        return Err(invalid_input("cannot pick 0 batteries".to_string()));
+    }
+    if let Some(battery) = input.chars().find(|c| !c.is_ascii_digit()) {
+        return Err(invalid_data(format!(
+            "invalid battery '{battery}' in bank '{input}'"
+        )));
+    }
+    if input.len() < size {
+        return Err(invalid_data(format!(
+            "cannot pick {size} batteries from a bank of {}",
+            input.len()
+        )));
+    }
+    Ok(())
+}
+
+fn selection(input_byte: &[u8], indices: Vec<usize>) -> io::Result<Selection> {
+    // as many digits as picked, a u64 would overflow past 19 of them
+    let digits: Vec<u8> = indices.iter().map(|&idx| input_byte[idx]).collect();
+    let value = BigUint::parse_bytes(&digits, 10).ok_or_else(|| {
+        invalid_data(format!(
+            "cannot read '{}' as a number",
+            String::from_utf8_lossy(&digits)
+        ))
+    })?;
+    Ok(Selection { value, indices })
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Objective {
+    #[default]
+    Max,
+    Min,
+}
+
+impl FromStr for Objective {
+    type Err = io::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "max" => Ok(Objective::Max),
+            "min" => Ok(Objective::Min),
+            _ => Err(invalid_input(format!(
+                "unknown objective '{s}', expected 'max' or 'min'"
+            ))),
+        }
+    }
+}
+
+impl Objective {
+    // whether digit `a` beats digit `b`
+    fn prefers(self, a: u8, b: u8) -> bool {
+        match self {
+            Objective::Max => a > b,
+            Objective::Min => a < b,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum LeadingZeros {
+    #[default]
+    Allow,
+    // the first picked battery must not be a 0
+    Forbid,
+}
+
+// which reading of the bank is wanted, the largest one with leading zeros allowed by default
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct Reading {
+    pub objective: Objective,
+    pub leading_zeros: LeadingZeros,
+}
+
+impl Reading {
+    pub const FLAGS: &'static [Flag] = &[
+        Flag::value("--objective"),
+        Flag::switch("--no-leading-zero"),
+    ];
+
+    // `--objective <max|min>` (or `--objective=<..>`) and `--no-leading-zero`
+    pub fn from_args(args: &Args) -> io::Result<Reading> {
+        let objective = args
+            .value("--objective")
+            .map_or(Ok(Objective::default()), str::parse)?;
+        let leading_zeros = if args.has("--no-leading-zero") {
+            LeadingZeros::Forbid
+        } else {
+            LeadingZeros::Allow
+        };
+        Ok(Reading {
+            objective,
+            leading_zeros,
+        })
+    }
+}
+
+// picks `size` digits of input_byte[from..] for the objective: a digit is popped while
+// a preferred one follows and drops remain, each index is pushed and popped at most once
+// so this is linear in the bank length
+fn stack_select(input_byte: &[u8], from: usize, size: usize, objective: Objective) -> Vec<usize> {
+    let mut drops = input_byte.len() - from - size;
+    let mut stack: Vec<usize> = Vec::with_capacity(input_byte.len() - from);
+    for (idx, &digit) in input_byte.iter().enumerate().skip(from) {
+        // equal digits stay, keeping the first of them like the window scan
+        while drops > 0
+            && stack
+                .last()
+                .is_some_and(|&top| objective.prefers(digit, input_byte[top]))
+        {
+            stack.pop();
+            drops -= 1;
+        }
+        stack.push(idx);
+    }
+    // drops left over come off the end
+    stack.truncate(size);
+    stack
+}
+
+// fails on an invalid bank or size, and when every reading would start with a forbidden 0
+pub fn select_batteries_with(input: &str, size: usize, reading: &Reading) -> io::Result<Selection> {
+    check_bank(input, size)?;
+    let input_byte = input.as_bytes();
+
+    let indices = match reading.leading_zeros {
+        LeadingZeros::Forbid => {
+            // the first occurrence of the preferred non zero digit leaving size - 1 batteries
+            // after it, the earliest one keeps the most choice for the rest
+            let mut first: Option<usize> = None;
+            for (idx, &digit) in input_byte[..=input.len() - size].iter().enumerate() {
+                if digit != b'0'
+                    && first.is_none_or(|f| reading.objective.prefers(digit, input_byte[f]))
+                {
+                    first = Some(idx);
+                }
+            }
+            let first = first.ok_or_else(|| {
+                invalid_data(format!(
+                    "every {size} batteries reading of '{input}' starts with 0"
+                ))
+            })?;
+            let mut indices = vec![first];
+            indices.extend(stack_select(
+                input_byte,
+                first + 1,
+                size - 1,
+                reading.objective,
+            ));
+            indices
+        }
+        LeadingZeros::Allow => stack_select(input_byte, 0, size, reading.objective),
+    };
+    selection(input_byte, indices)
+}
+
+pub fn select_batteries(input: &str, size: usize) -> io::Result<Selection> {
+    select_batteries_with(input, size, &Reading::default())
+}
+
+// reference version in O(len * size), the loop of the first solution: each digit starts at
+// '0' and only moves to a strictly larger one of the window leaving enough batteries for the
+// remaining picks, so the first of equal digits stays.
+// on a window of zeros the first solution never moved and searched from the same place for
+// the next digit, which reads the same value. here the first zero is picked and skipped so
+// the indices stay increasing
+pub fn select_batteries_scan(input: &str, size: usize) -> io::Result<Selection> {
+    check_bank(input, size)?;
+    let input_byte = input.as_bytes();
+
+    let mut result_str = vec![b'0'; size];
+    let mut indices = Vec::with_capacity(size);
+    let mut search_begin: usize = 0;
+    for result_i in 0..result_str.len() {
+        let search_limit = input_byte.len() - result_str.len() + result_i + 1;
+        let mut picked = search_begin;
+        let window = input_byte.iter().enumerate().take(search_limit);
+        for (input_i, &digit) in window.skip(search_begin) {
+            if digit > result_str[result_i] {
+                result_str[result_i] = digit;
+                picked = input_i;
+                search_begin = input_i + 1;
+            }
+        }
+        if result_str[result_i] == b'0' {
+            search_begin = picked + 1;
+        }
+        indices.push(picked);
+    }
+    selection(input_byte, indices)
+}
+
+pub fn compute_joltage(input: &str, size: usize) -> io::Result<BigUint> {
+    select_batteries(input, size).map(|selection| selection.value)
+}
+
+pub fn compute_joltage_with(input: &str, size: usize, reading: &Reading) -> io::Result<BigUint> {
+    select_batteries_with(input, size, reading).map(|selection| selection.value)
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ShowFormat {
+    // picked digits in bold green
+    #[default]
+    Color,
+    // digits left out replaced by dots
+    Plain,
+}
+
+impl FromStr for ShowFormat {
+    type Err = io::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "color" => Ok(ShowFormat::Color),
+            "plain" => Ok(ShowFormat::Plain),
+            _ => Err(invalid_input(format!(
+                "unknown show format '{s}', expected 'color' or 'plain'"
+            ))),
+        }
+    }
+}
+
+impl ShowFormat {
+    pub const FLAGS: &'static [Flag] = &[Flag::optional_value("--show")];
+
+    // `--show` highlights in color, `--show=plain` with dots, None without them
+    pub fn from_args(args: &Args) -> io::Result<Option<ShowFormat>> {
+        args.last("--show")
+            .map(|value| value.map_or(Ok(ShowFormat::Color), str::parse))
+            .transpose()
+    }
+
+    pub fn highlight(&self, bank: &str, selection: &Selection) -> String {
+        let mut picked = selection.indices.iter().peekable();
+        bank.char_indices()
+            .map(|(idx, digit)| {
+                let chosen = picked.next_if_eq(&&idx).is_some();
+                match (self, chosen) {
+                    (ShowFormat::Color, true) => format!("\x1b[1;32m{digit}\x1b[0m"),
+                    (ShowFormat::Plain, false) => ".".to_string(),
+                    _ => digit.to_string(),
+                }
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse_args(a: &[&str], flags: &[Flag]) -> io::Result<Args> {
+        Args::parse(a.iter().map(|s| s.to_string()), flags)
+    }
+
+    #[test]
+    fn test_compute_joltage() {
+        assert_eq!(compute_joltage("987654321111111", 2).unwrap(), 98u64.into());
+        assert_eq!(compute_joltage("811111111111119", 2).unwrap(), 89u64.into());
+        assert_eq!(compute_joltage("234234234234278", 2).unwrap(), 78u64.into());
+        assert_eq!(compute_joltage("818181911112111", 2).unwrap(), 92u64.into());
+
+        assert_eq!(
+            compute_joltage("987654321111111", 12).unwrap(),
+            987654321111u64.into()
+        );
+        assert_eq!(
+            compute_joltage("811111111111119", 12).unwrap(),
+            811111111119u64.into()
+        );
+        assert_eq!(
+            compute_joltage("234234234234278", 12).unwrap(),
+            434234234278u64.into()
+        );
+        assert_eq!(
+            compute_joltage("818181911112111", 12).unwrap(),
+            888911112111u64.into()
+        );
+    }
+
+    #[test]
+    fn test_invalid_banks() {
+        let err = |bank: &str, size| compute_joltage(bank, size).unwrap_err().to_string();
+        assert_eq!(err("123", 0), "cannot pick 0 batteries");
+        assert_eq!(err("12", 3), "cannot pick 3 batteries from a bank of 2");
+        assert_eq!(err("12a4", 2), "invalid battery 'a' in bank '12a4'");
+        assert!(select_batteries_scan("", 0).is_err());
+        assert!(select_batteries_scan("1 2", 1).is_err());
+    }
+
+    #[test]
+    fn test_select_batteries() {
+        let selection = select_batteries("818181911112111", 12).unwrap();
+        assert_eq!(selection.value, 888911112111u64.into());
+        assert_eq!(selection.indices, [0, 2, 4, 6, 7, 8, 9, 10, 11, 12, 13, 14]);
+        assert_eq!(
+            select_batteries("811111111111119", 2).unwrap().indices,
+            [0, 14]
+        );
+        // the first of equal digits, zeros included
+        assert_eq!(select_batteries("9090", 2).unwrap().indices, [0, 2]);
+        assert_eq!(select_batteries("000", 2).unwrap().indices, [0, 1]);
+        // every picked index reads back the value
+        for bank in ["987654321111111", "234234234234278"] {
+            let selection = select_batteries(bank, 12).unwrap();
+            let digits: String = selection.indices.iter().map(|&i| &bank[i..=i]).collect();
+            assert_eq!(digits, selection.value.to_string());
+            assert!(selection.indices.is_sorted());
+        }
+    }
+
+    // a bank of `len` digits following a xorshift, never starting with 0
+    fn random_bank(len: usize, seed: u64) -> String {
+        let mut state = seed;
+        (0..len)
+            .map(|idx| {
+                state ^= state << 13;
+                state ^= state >> 7;
+                state ^= state << 17;
+                let digit = if idx == 0 { 1 + state % 9 } else { state % 10 };
+                char::from_digit(digit as u32, 10).unwrap()
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_wide_joltage() {
+        let bank = random_bank(100, 42);
+        // picking every battery reads the bank back
+        assert_eq!(compute_joltage(&bank, 100).unwrap().to_string(), bank);
+        let nines = "9".repeat(60) + &"1".repeat(40);
+        assert_eq!(
+            compute_joltage(&nines, 60).unwrap().to_string(),
+            "9".repeat(60)
+        );
+        assert_eq!(
+            compute_joltage(&nines, 61).unwrap().to_string(),
+            "9".repeat(60) + "1"
+        );
+        // a thousand 100 digits joltages, far beyond u128
+        let total: BigUint = (0..1000)
+            .map(|_| compute_joltage(&"9".repeat(100), 100).unwrap())
+            .sum();
+        assert_eq!(total.to_string(), "9".repeat(100) + "000");
+        // the picked indices read back the 40 digits value
+        for seed in 1..20 {
+            let bank = random_bank(100, seed);
+            let selection = select_batteries(&bank, 40).unwrap();
+            assert_eq!(selection.value.to_string().len(), 40);
+            let digits: String = selection.indices.iter().map(|&i| &bank[i..=i]).collect();
+            assert_eq!(digits, selection.value.to_string());
+        }
+    }
+
+    #[test]
+    fn test_stack_matches_scan() {
+        for seed in 1..200 {
+            let len = 1 + seed as usize % 60;
+            // few distinct digits give many ties
+            let bank: String = random_bank(len, seed)
+                .bytes()
+                .map(|b| if seed % 2 == 0 { b } else { b'1' + b % 3 })
+                .map(char::from)
+                .collect();
+            for size in 1..=len {
+                assert_eq!(
+                    select_batteries(&bank, size).unwrap(),
+                    select_batteries_scan(&bank, size).unwrap(),
+                    "{bank} {size}"
+                );
+            }
+        }
+        assert_eq!(
+            select_batteries("000", 2).unwrap(),
+            select_batteries_scan("000", 2).unwrap()
+        );
+    }
+
+    // the loop of the first solution as it was
+    #[allow(clippy::needless_range_loop, clippy::mut_range_bound)]
+    fn first_solution(input: &str, size: usize) -> String {
+        let input_byte = input.as_bytes();
+        let mut result_str = Vec::new();
+        result_str.resize(size, b'0');
+
+        let mut search_begin: usize = 0;
+        for result_i in 0..result_str.len() {
+            let search_limit = input_byte.len() - result_str.len() + result_i + 1;
+            for input_i in search_begin..search_limit {
+                if input_byte[input_i] > result_str[result_i] {
+                    result_str[result_i] = input_byte[input_i];
+                    search_begin = input_i + 1;
+                }
+            }
+        }
+        String::from_utf8(result_str).unwrap()
+    }
+
+    #[test]
+    fn test_scan_matches_first_solution() {
+        // windows of zeros are where the scan differs from the first solution
+        assert_eq!(first_solution("009", 3), "009");
+        assert_eq!(select_batteries_scan("009", 3).unwrap().indices, [0, 1, 2]);
+        assert_eq!(select_batteries_scan("0900", 3).unwrap().indices, [1, 2, 3]);
+        for seed in 1..200 {
+            let len = 1 + seed as usize % 40;
+            // mostly zeros
+            let bank: String = random_bank(len, seed)
+                .bytes()
+                .map(|b| if b % 3 == 0 { b } else { b'0' })
+                .map(char::from)
+                .collect();
+            for size in 1..=len {
+                let scan = select_batteries_scan(&bank, size).unwrap();
+                assert!(scan.indices.is_sorted_by(|a, b| a < b), "{bank} {size}");
+                let digits: String = scan.indices.iter().map(|&i| &bank[i..=i]).collect();
+                assert_eq!(digits, first_solution(&bank, size), "{bank} {size}");
+                assert_eq!(
+                    scan,
+                    select_batteries(&bank, size).unwrap(),
+                    "{bank} {size}"
+                );
+            }
+        }
+    }
+
+    // every reading of `size` digits, at most 2^len of them
+    fn all_readings(bank: &str, size: usize) -> Vec<String> {
+        (0u32..1 << bank.len())
+            .filter(|mask| mask.count_ones() as usize == size)
+            .map(|mask| {
+                bank.chars()
+                    .enumerate()
+                    .filter(|(idx, _)| mask >> idx & 1 == 1)
+                    .map(|(_, digit)| digit)
+                    .collect()
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_min_joltage() {
+        let min = Reading {
+            objective: Objective::Min,
+            leading_zeros: LeadingZeros::Forbid,
+        };
+        let joltage = |bank, size| compute_joltage_with(bank, size, &min).unwrap().to_string();
+        assert_eq!(joltage("987654321111111", 2), "11");
+        assert_eq!(joltage("811111111111119", 2), "11");
+        assert_eq!(joltage("234234234234278", 2), "22");
+        assert_eq!(joltage("818181911112111", 2), "11");
+
+        assert_eq!(joltage("987654321111111", 12), "654321111111");
+        assert_eq!(joltage("811111111111119", 12), "111111111111");
+        assert_eq!(joltage("234234234234278", 12), "223234234278");
+        assert_eq!(joltage("818181911112111", 12), "111911112111");
+        let selection = select_batteries_with("818181911112111", 12, &min).unwrap();
+        assert_eq!(
+            ShowFormat::Plain.highlight("818181911112111", &selection),
+            ".1.1.1911112111"
+        );
+
+        // zeros only matter in first position
+        assert_eq!(joltage("100200300", 3), "100");
+        assert_eq!(joltage("9000001", 2), "90");
+        let zeros = Reading {
+            objective: Objective::Min,
+            leading_zeros: LeadingZeros::Allow,
+        };
+        assert_eq!(
+            compute_joltage_with("100200300", 3, &zeros).unwrap(),
+            0u32.into()
+        );
+        let selection = select_batteries_with("9000001", 3, &zeros).unwrap();
+        assert_eq!(selection.indices, [1, 2, 3]);
+        let err = compute_joltage_with("0009", 2, &min).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "every 2 batteries reading of '0009' starts with 0"
+        );
+        let max = Reading {
+            objective: Objective::Max,
+            leading_zeros: LeadingZeros::Forbid,
+        };
+        assert!(compute_joltage_with("0009", 2, &max).is_err());
+        assert_eq!(compute_joltage_with("0090", 2, &max).unwrap(), 90u32.into());
+    }
+
+    #[test]
+    fn test_readings_against_brute_force() {
+        for seed in 1..120 {
+            let len = 1 + seed as usize % 10;
+            // plenty of zeros
+            let bank: String = random_bank(len, seed)
+                .bytes()
+                .map(|b| char::from(b'0' + b % 4))
+                .collect();
+            for size in 1..=len {
+                let readings = all_readings(&bank, size);
+                for objective in [Objective::Max, Objective::Min] {
+                    for leading_zeros in [LeadingZeros::Allow, LeadingZeros::Forbid] {
+                        let candidates = readings.iter().filter(|r| {
+                            leading_zeros == LeadingZeros::Allow || !r.starts_with('0')
+                        });
+                        let expected = match objective {
+                            Objective::Max => candidates.max(),
+                            Objective::Min => candidates.min(),
+                        };
+                        let reading = Reading {
+                            objective,
+                            leading_zeros,
+                        };
+                        let selection = select_batteries_with(&bank, size, &reading).ok();
+                        let digits = selection
+                            .map(|s| s.indices.iter().map(|&i| &bank[i..=i]).collect::<String>());
+                        assert_eq!(
+                            digits.as_ref(),
+                            expected,
+                            "{bank} {size} {objective:?} {leading_zeros:?}"
+                        );
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_reading_args() {
+        let args =
+            |a: &[&str]| parse_args(a, Reading::FLAGS).and_then(|args| Reading::from_args(&args));
+        assert_eq!(args(&[]).unwrap(), Reading::default());
+        assert_eq!(
+            args(&["--objective", "min", "--no-leading-zero"]).unwrap(),
+            Reading {
+                objective: Objective::Min,
+                leading_zeros: LeadingZeros::Forbid
+            }
+        );
+        assert_eq!(
+            args(&["--objective=max"]).unwrap().objective,
+            Objective::Max
+        );
+        assert!(args(&["--objective=median"]).is_err());
+        assert!(args(&["--objective"]).is_err());
+        assert!(args(&["--no-leading-zeros"]).is_err());
+    }
+
+    #[test]
+    fn test_highlight() {
+        let selection = select_batteries("818181911112111", 12).unwrap();
+        assert_eq!(
+            ShowFormat::Plain.highlight("818181911112111", &selection),
+            "8.8.8.911112111"
+        );
+        let selection = select_batteries("1234", 1).unwrap();
+        assert_eq!(
+            ShowFormat::Color.highlight("1234", &selection),
+            "123\x1b[1;32m4\x1b[0m"
+        );
+
+        let args = |a: &[&str]| {
+            parse_args(a, ShowFormat::FLAGS).and_then(|args| ShowFormat::from_args(&args))
+        };
+        assert_eq!(args(&[]).unwrap(), None);
+        assert_eq!(args(&["--show"]).unwrap(), Some(ShowFormat::Color));
+        assert_eq!(args(&["--show=plain"]).unwrap(), Some(ShowFormat::Plain));
+        assert!(args(&["--show=html"]).is_err());
+        assert!(args(&["--pick", "2"]).is_err());
+    }
+
+    #[test]
+    fn test_picks_args() {
+        let args = |a: &[&str]| parse_args(a, PICK_FLAGS).and_then(|args| picks_from_args(&args));
+        assert_eq!(args(&[]).unwrap(), [2, 12]);
+        assert_eq!(args(&["--pick", "2,12"]).unwrap(), [2, 12]);
+        assert_eq!(args(&["--pick=3", "--pick", "1, 5"]).unwrap(), [3, 1, 5]);
+        for bad in [
+            &["--pick"][..],
+            &["--pick", "0"],
+            &["--pick=2,"],
+            &["--pick=x"],
+            &["--picks", "2"],
+        ] {
+            assert!(args(bad).is_err(), "{bad:?}");
+        }
+    }
+}
@@ -0,0 +1,43 @@
+use aoc2025_day3::{
+    Objective, PICK_FLAGS, Reading, ShowFormat, picks_from_args, select_batteries_with,
+};
+use common::args::Args;
+use num_bigint::BigUint;
+use std::env;
+use std::fs::File;
+use std::io::{self, BufRead, BufReader};
+
+fn main() -> io::Result<()> {
+    let args = Args::from_env(&[PICK_FLAGS, ShowFormat::FLAGS, Reading::FLAGS].concat())?;
+    let picks = picks_from_args(&args)?;
+    let show = ShowFormat::from_args(&args)?;
+    let reading = Reading::from_args(&args)?;
+    let file =
+        File::open(env::current_dir()?.join("src/input.txt")).expect("failed to read input.txt");
+    let reader = BufReader::new(file);
+
+    let mut sums = vec![BigUint::default(); picks.len()];
+    for (idx, line) in reader.lines().enumerate() {
+        let line = line?;
+        let bank = line.trim();
+        if bank.is_empty() {
+            continue;
+        }
+        for (sum, &size) in sums.iter_mut().zip(&picks) {
+            let selection = select_batteries_with(bank, size, &reading)
+                .map_err(|err| io::Error::new(err.kind(), format!("line {}: {err}", idx + 1)))?;
+            if let Some(format) = show {
+                println!("{} {}", format.highlight(bank, &selection), selection.value);
+            }
+            *sum += &selection.value;
+        }
+    }
+    for (size, sum) in picks.iter().zip(sums) {
+        match reading.objective {
+            Objective::Max => println!("sum of joltage picking {size} batteries: {sum}"),
+            Objective::Min => println!("sum of minimum joltage picking {size} batteries: {sum}"),
+        }
+    }
+
+    Ok(())
+}
@@ -0,0 +1,21 @@
+use aoc2025_day4::remove_all_rolls;
+use std::env;
+use std::fs::File;
+use std::io::{self, BufRead, BufReader};
+
+fn main() -> io::Result<()> {
+    let file = File::open(env::current_dir()?.join("src/input.txt"))?;
+    let reader = BufReader::new(file);
+
+    let mut roll_map: Vec<Vec<u8>> = Vec::new();
+
+    for line in reader.lines() {
+        roll_map.push(Vec::from(line?.as_bytes()));
+    }
+
+    let total_roll_processed = remove_all_rolls(roll_map);
+
+    println!("stuck rolled: {total_roll_processed}");
+
+    Ok(())
+}
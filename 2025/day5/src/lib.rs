@@ -1,23 +1,22 @@
 use std::{
     cmp::{max, min},
-    env,
-    fmt::Debug,
-    fs::File,
-    io::{self, BufRead, BufReader},
+    str::FromStr,
 };
 
 #[derive(PartialEq, Eq, PartialOrd, Ord, Debug, Clone)]
-struct RangeId {
-    min: u64,
-    max: u64,
+pub struct RangeId {
+    pub min: u64,
+    pub max: u64,
 }
 
 #[derive(Debug, Clone)]
-struct LineParseError {
-    badline: String,
+pub struct LineParseError {
+    pub badline: String,
 }
 
-impl RangeId {
+impl FromStr for RangeId {
+    type Err = LineParseError;
+
     fn from_str(line: &str) -> Result<RangeId, LineParseError> {
         let option = line.split_once('-');
         match option {
@@ -42,11 +41,11 @@ impl RangeId {
     }
 }
 
-fn in_range(range: &RangeId, id: u64) -> bool {
+pub fn in_range(range: &RangeId, id: u64) -> bool {
     return id >= range.min && id <= range.max;
 }
 
-fn merge_range(lval: &RangeId, rval: &RangeId) -> Option<RangeId> {
+pub fn merge_range(lval: &RangeId, rval: &RangeId) -> Option<RangeId> {
     if in_range(lval, rval.min) || in_range(rval, lval.min) {
         return Some(RangeId {
             min: min(lval.min, rval.min),
@@ -73,7 +72,7 @@ fn consolidate_ranges_once(ids: &Vec<RangeId>, idx: usize) -> Vec<RangeId> {
     return result;
 }
 
-fn consolidate_ranges(mut ids: Vec<RangeId>) -> Vec<RangeId> {
+pub fn consolidate_ranges(mut ids: Vec<RangeId>) -> Vec<RangeId> {
     let mut idx: usize = 0;
     loop {
         if idx >= ids.len() {
@@ -204,46 +203,3 @@ mod tests {
         assert_eq!(expect2, result);
     }
 }
-
-fn main() -> io::Result<()> {
-    let file = File::open(env::current_dir()?.join("src/input.txt"))?;
-    let reader = BufReader::new(file);
-
-    let mut sum_indredient_fresh = 0;
-    let mut valid_range_ids = Vec::new();
-    let mut parsing_range = true;
-    for line_result in reader.lines() {
-        let line = line_result?;
-        if line.len() == 0 {
-            parsing_range = false;
-            valid_range_ids = consolidate_ranges(valid_range_ids);
-            continue;
-        }
-        if parsing_range {
-            valid_range_ids.push(
-                RangeId::from_str(line.as_str())
-                    .map_err(|err| io::Error::new(io::ErrorKind::InvalidData, err.badline))?,
-            );
-        } else {
-            let id_to_check: u64 = line
-                .parse()
-                .expect(format!("failed to parse line {}", line).as_str());
-            let mut is_valid = false;
-
-            for range in &valid_range_ids {
-                is_valid = is_valid || in_range(&range, id_to_check)
-            }
-            if is_valid {
-                sum_indredient_fresh += 1;
-            }
-        }
-    }
-    let mut sum_id_fresh = 0;
-
-    for range in &valid_range_ids {
-        sum_id_fresh += range.max - range.min + 1;
-    }
-    println!("total number of fresh ingretient: {}", sum_indredient_fresh);
-    println!("total number of fresh ids: {}", sum_id_fresh);
-    Ok(())
-}
@@ -0,0 +1,49 @@
+use aoc2025_day5::{RangeId, consolidate_ranges, in_range};
+use std::{
+    env,
+    fs::File,
+    io::{self, BufRead, BufReader},
+};
+
+fn main() -> io::Result<()> {
+    let file = File::open(env::current_dir()?.join("src/input.txt"))?;
+    let reader = BufReader::new(file);
+
+    let mut sum_indredient_fresh = 0;
+    let mut valid_range_ids = Vec::new();
+    let mut parsing_range = true;
+    for line_result in reader.lines() {
+        let line = line_result?;
+        if line.len() == 0 {
+            parsing_range = false;
+            valid_range_ids = consolidate_ranges(valid_range_ids);
+            continue;
+        }
+        if parsing_range {
+            valid_range_ids.push(
+                line.parse::<RangeId>()
+                    .map_err(|err| io::Error::new(io::ErrorKind::InvalidData, err.badline))?,
+            );
+        } else {
+            let id_to_check: u64 = line
+                .parse()
+                .expect(format!("failed to parse line {}", line).as_str());
+            let mut is_valid = false;
+
+            for range in &valid_range_ids {
+                is_valid = is_valid || in_range(&range, id_to_check)
+            }
+            if is_valid {
+                sum_indredient_fresh += 1;
+            }
+        }
+    }
+    let mut sum_id_fresh = 0;
+
+    for range in &valid_range_ids {
+        sum_id_fresh += range.max - range.min + 1;
+    }
+    println!("total number of fresh ingretient: {}", sum_indredient_fresh);
+    println!("total number of fresh ids: {}", sum_id_fresh);
+    Ok(())
+}
@@ -0,0 +1,165 @@
+use common::numeric::{Num, NumericMode};
+use std::io;
+
+pub fn process_sub_matrix(
+    mat: &Vec<Vec<u8>>,
+    begin: usize,
+    end: usize,
+    mode: NumericMode,
+) -> Result<Num, io::Error> {
+    let num_max_size = mat.len();
+    let op = mat.last().unwrap()[begin];
+    let mut acc = Num::new(mode, 0);
+    let mut num_bytes = Vec::new();
+    num_bytes.reserve(num_max_size);
+
+    for col in begin..end {
+        num_bytes.clear();
+        for line in 0..mat.len() - 1 {
+            if mat[line][col] != b' ' {
+                num_bytes.push(mat[line][col]);
+            }
+        }
+        let num = Num::parse(
+            mode,
+            str::from_utf8(num_bytes.as_slice()).map_err(|err| {
+                io::Error::new(
+                    io::ErrorKind::InvalidInput,
+                    format!("failed to recast into str: {err}"),
+                )
+            })?,
+        )?;
+        if acc.is_zero() {
+            acc = num
+        } else {
+            let overflow = |err| {
+                io::Error::new(
+                    io::ErrorKind::InvalidData,
+                    format!("overflow at column {col}: {err}"),
+                )
+            };
+            match op {
+                b'+' => acc = acc.try_add(&num).map_err(overflow)?,
+                b'*' => acc = acc.try_mul(&num).map_err(overflow)?,
+                _ => {
+                    return Err(io::Error::new(
+                        io::ErrorKind::InvalidInput,
+                        format!("unexepected op '{op}'"),
+                    ));
+                }
+            }
+        }
+    }
+
+    Ok(acc)
+}
+
+pub fn grand_total(matrix: &Vec<Vec<u8>>, mode: NumericMode) -> Result<Num, io::Error> {
+    let mut result = Num::new(mode, 0);
+    if matrix.len() == 0 {
+        return Ok(result);
+    }
+
+    let mut begin = 0;
+    let op = matrix.last().unwrap();
+    for tok_index in 1..op.len() {
+        if op[tok_index] != b' ' {
+            result = result.try_add(&process_sub_matrix(matrix, begin, tok_index - 1, mode)?)?;
+            begin = tok_index;
+        }
+    }
+    //process last matrix
+    result = result.try_add(&process_sub_matrix(matrix, begin, op.len(), mode)?)?;
+
+    Ok(result)
+}
+
+#[test]
+fn test_process_sub_matric() {
+    let matrix: Vec<Vec<u8>> = vec![vec![b'1'], vec![b'2'], vec![b'2'], vec![b'+']];
+
+    let result = process_sub_matrix(&matrix, 0, 1, NumericMode::Checked);
+    assert!(result.is_ok());
+    assert_eq!(result.unwrap(), 122);
+
+    let matrix: Vec<Vec<u8>> = vec![
+        vec![b'1', b'2'],
+        vec![b'2', b' '],
+        vec![b'2', b' '],
+        vec![b'+', b' '],
+    ];
+
+    let result = process_sub_matrix(&matrix, 0, 2, NumericMode::Checked);
+    assert!(result.is_ok());
+    assert_eq!(result.unwrap(), 124);
+
+    let matrix: Vec<Vec<u8>> = vec![
+        vec![b'1', b'2'],
+        vec![b'2', b'2'],
+        vec![b'2', b' '],
+        vec![b'+', b' '],
+    ];
+
+    let result = process_sub_matrix(&matrix, 0, 2, NumericMode::Checked);
+    assert!(result.is_ok());
+    assert_eq!(result.unwrap(), 144);
+
+    let matrix: Vec<Vec<u8>> = vec![
+        vec![b'1', b'2'],
+        vec![b'2', b'2'],
+        vec![b'2', b' '],
+        vec![b'*', b' '],
+    ];
+
+    let result = process_sub_matrix(&matrix, 0, 2, NumericMode::Checked);
+    assert!(result.is_ok());
+    assert_eq!(result.unwrap(), 122 * 22);
+
+    let matrix: Vec<Vec<u8>> = vec![
+        vec![b'1', b'2'],
+        vec![b'2', b'a'],
+        vec![b'2', b' '],
+        vec![b'*', b' '],
+    ];
+
+    let result = process_sub_matrix(&matrix, 0, 2, NumericMode::Checked);
+    assert!(result.is_err());
+
+    let matrix: Vec<Vec<u8>> = vec![
+        vec![b'1', b'2'],
+        vec![b'2', b'2'],
+        vec![b'2', b' '],
+        vec![b'/', b' '],
+    ];
+
+    let result = process_sub_matrix(&matrix, 0, 2, NumericMode::Checked);
+    assert!(result.is_err());
+
+    let matrix: Vec<Vec<u8>> = vec![
+        b"9999999999".to_vec(),
+        b"9999999999".to_vec(),
+        b"*         ".to_vec(),
+    ];
+
+    // 10 columns of 99 multiplied together overflow 64 bits
+    let result = process_sub_matrix(&matrix, 0, 10, NumericMode::Checked);
+    assert!(result.is_err());
+    let result = process_sub_matrix(&matrix, 0, 10, NumericMode::Big);
+    assert_eq!(result.unwrap().to_string(), "90438207500880449001");
+}
+
+#[test]
+fn test_grand_total() {
+    let matrix: Vec<Vec<u8>> = vec![
+        b"123 328  51 64 ".to_vec(),
+        b" 45 64  387 23 ".to_vec(),
+        b"  6 98  215 314".to_vec(),
+        b"*   +   *   +  ".to_vec(),
+    ];
+
+    let result = grand_total(&matrix, NumericMode::Checked);
+    assert_eq!(result.unwrap(), 3263827);
+
+    let result = grand_total(&Vec::new(), NumericMode::Checked);
+    assert_eq!(result.unwrap(), 0);
+}
@@ -0,0 +1,25 @@
+use aoc2025_day6::grand_total;
+use common::args::Args;
+use common::numeric::NumericMode;
+use std::{
+    env,
+    fs::File,
+    io::{self, BufRead, BufReader},
+};
+
+fn main() -> io::Result<()> {
+    let mode = NumericMode::from_args(&Args::from_env(NumericMode::FLAGS)?)?;
+    let file = File::open(env::current_dir()?.join("src/input.txt"))?;
+    let reader = BufReader::new(file);
+
+    let mut matrix: Vec<Vec<u8>> = Vec::new();
+
+    for line in reader.lines() {
+        matrix.push(line?.as_bytes().to_vec());
+    }
+
+    let result = grand_total(&matrix, mode)?;
+    println!("grand total of operation: {result}");
+
+    Ok(())
+}
@@ -0,0 +1,212 @@
+use common::numeric::{Num, NumericMode, OverflowError};
+use std::{
+    collections::HashMap, io
+};
+
+fn add_timeline(next_timeline: &mut HashMap<(usize, usize), Num>, key: (usize, usize), val: &Num) -> Result<(), OverflowError> {
+    let new_val = match next_timeline.get(&key) {
+        Some(current) => current.try_add(val)?,
+        None => val.clone(),
+    };
+    next_timeline.insert(key, new_val);
+    Ok(())
+}
+
+fn propagate_quantum_tachyon_timeline(matrix: &Vec<Vec<u8>>, timelines: &HashMap<(usize, usize), Num>) -> Result<HashMap<(usize, usize), Num>, OverflowError> {
+    let mut next_timeline =  HashMap::new();
+    next_timeline.reserve(timelines.len() * 2);
+
+    for (cur_timeline, val) in timelines {
+        if matrix[cur_timeline.0][cur_timeline.1] == b'^' {
+            if cur_timeline.1 > 0 {
+                add_timeline(&mut next_timeline, (cur_timeline.0 + 1 ,cur_timeline.1 - 1), val)?;
+            }
+            if cur_timeline.1 < matrix[cur_timeline.0].len() - 1 {
+                add_timeline(&mut next_timeline, (cur_timeline.0 + 1 ,cur_timeline.1 + 1), val)?;
+            }
+        } else {
+            add_timeline(&mut next_timeline, (cur_timeline.0 + 1, cur_timeline.1), val)?;
+        }
+    }
+    Ok(next_timeline)
+}
+
+pub fn propagate_quantum_tachyon(matrix: &Vec<Vec<u8>>, line: usize, col: usize, mode: NumericMode) -> io::Result<Num> {
+    let mut timelines: HashMap<(usize, usize), Num> = HashMap::from([((line, col), Num::new(mode, 1))]);
+
+    for cur_line in line..matrix.len() {
+        timelines = propagate_quantum_tachyon_timeline(matrix, &timelines).map_err(|err| {
+            io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!("timeline count at line {cur_line}: {err}"),
+            )
+        })?;
+    }
+    
+    let mut result = Num::new(mode, 0);
+    for (_, count) in timelines {
+        result = result.try_add(&count)?;
+    }
+
+    Ok(result)
+}
+
+// the beam starts right under the S
+pub fn find_start(matrix: &[Vec<u8>]) -> (usize, usize) {
+    let mut s_line = 0;
+    let mut s_col = 0;
+    for line in 0..matrix.len() {
+        for col in 0..matrix[0].len() {
+            if matrix[line][col] == b'S' {
+                s_line = line + 1;
+                s_col = col;
+            }
+        }
+    }
+    (s_line, s_col)
+}
+
+// mark with '|' every cell a beam goes through
+pub fn draw_beams(matrix: &[Vec<u8>], line: usize, col: usize) -> Vec<Vec<u8>> {
+    let mut drawing = matrix.to_vec();
+    let mut beams = vec![col];
+
+    for cur_line in line..matrix.len() {
+        let mut next_beams = Vec::new();
+        for beam in beams {
+            if matrix[cur_line][beam] == b'^' {
+                if beam > 0 {
+                    next_beams.push(beam - 1);
+                }
+                if beam < matrix[cur_line].len() - 1 {
+                    next_beams.push(beam + 1);
+                }
+            } else {
+                drawing[cur_line][beam] = b'|';
+                next_beams.push(beam);
+            }
+        }
+        next_beams.sort();
+        next_beams.dedup();
+        beams = next_beams;
+    }
+    drawing
+}
+
+#[test]
+fn test_propagate_quantum_tachyon() {
+        let matrix =[
+".......S.......".as_bytes().to_vec(),
+"...............".as_bytes().to_vec(),
+".......^.......".as_bytes().to_vec(),
+"...............".as_bytes().to_vec(),
+"......^.^......".as_bytes().to_vec(),
+"...............".as_bytes().to_vec()].to_vec();
+
+    let mut s_line = 0;
+    let mut s_col = 0;
+    for line in 0..matrix.len() {
+        for col in 0..matrix[0].len() {
+            if matrix[line][col] == b'S' {
+                s_line = line + 1;
+                s_col = col;
+            }
+        }
+    }
+
+    let timeline = propagate_quantum_tachyon(&matrix, s_line, s_col, NumericMode::Checked).unwrap();
+    assert_eq!(timeline, 4);
+
+
+            let matrix =[
+".......S.......".as_bytes().to_vec(),
+"...............".as_bytes().to_vec(),
+".......^.......".as_bytes().to_vec(),
+"...............".as_bytes().to_vec(),
+"......^.^......".as_bytes().to_vec(),
+"...............".as_bytes().to_vec(),
+".....^.^.^.....".as_bytes().to_vec(),
+"...............".as_bytes().to_vec(),
+].to_vec();
+
+    let mut s_line = 0;
+    let mut s_col = 0;
+    for line in 0..matrix.len() {
+        for col in 0..matrix[0].len() {
+            if matrix[line][col] == b'S' {
+                s_line = line + 1;
+                s_col = col;
+            }
+        }
+    }
+
+    let timeline = propagate_quantum_tachyon(&matrix, s_line, s_col, NumericMode::Checked).unwrap();
+    assert_eq!(timeline, 8);
+
+    let matrix =[
+".......S.......".as_bytes().to_vec(),
+"...............".as_bytes().to_vec(),
+".......^.......".as_bytes().to_vec(),
+"...............".as_bytes().to_vec(),
+"......^.^......".as_bytes().to_vec(),
+"...............".as_bytes().to_vec(),
+".....^.^.^.....".as_bytes().to_vec(),
+"...............".as_bytes().to_vec(),
+"....^.^...^....".as_bytes().to_vec(),
+"...............".as_bytes().to_vec(),
+"...^.^...^.^...".as_bytes().to_vec(),
+"...............".as_bytes().to_vec(),
+"..^...^.....^..".as_bytes().to_vec(),
+"...............".as_bytes().to_vec(),
+".^.^.^.^.^...^.".as_bytes().to_vec(),
+"...............".as_bytes().to_vec()].to_vec();
+
+    let mut s_line = 0;
+    let mut s_col = 0;
+    for line in 0..matrix.len() {
+        for col in 0..matrix[0].len() {
+            if matrix[line][col] == b'S' {
+                s_line = line + 1;
+                s_col = col;
+            }
+        }
+    }
+
+    let timeline = propagate_quantum_tachyon(&matrix, s_line, s_col, NumericMode::Checked).unwrap();
+    assert_eq!(timeline, 40);
+}
+
+#[test]
+fn test_propagate_quantum_tachyon_overflow() {
+    // every other line is a full row of splitters, doubling the timelines each time
+    let mut matrix = vec![b"S".repeat(140)];
+    for _ in 0..70 {
+        matrix.push(b".".repeat(140));
+        matrix.push(b"^".repeat(140));
+    }
+
+    let timeline = propagate_quantum_tachyon(&matrix, 1, 70, NumericMode::Checked);
+    assert!(timeline.is_err());
+
+    let timeline = propagate_quantum_tachyon(&matrix, 1, 70, NumericMode::Big).unwrap();
+    assert!(timeline > Num::new(NumericMode::Big, i64::MAX));
+}
+
+#[test]
+fn test_draw_beams() {
+    let matrix = [
+".......S.......".as_bytes().to_vec(),
+"...............".as_bytes().to_vec(),
+".......^.......".as_bytes().to_vec(),
+"...............".as_bytes().to_vec(),
+"......^.^......".as_bytes().to_vec(),
+"...............".as_bytes().to_vec()].to_vec();
+
+    let (s_line, s_col) = find_start(&matrix);
+    let drawing = draw_beams(&matrix, s_line, s_col);
+    assert_eq!(drawing[1], ".......|.......".as_bytes().to_vec());
+    assert_eq!(drawing[2], ".......^.......".as_bytes().to_vec());
+    assert_eq!(drawing[3], "......|.|......".as_bytes().to_vec());
+    assert_eq!(drawing[4], "......^.^......".as_bytes().to_vec());
+    assert_eq!(drawing[5], ".....|.|.|.....".as_bytes().to_vec());
+}
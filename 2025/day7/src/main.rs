@@ -0,0 +1,24 @@
+use aoc2025_day7::{find_start, propagate_quantum_tachyon};
+use common::args::Args;
+use common::numeric::NumericMode;
+use std::{
+    env, fs::File, io::{self, BufRead, BufReader}
+};
+
+fn main() -> io::Result<()> {
+    let mode = NumericMode::from_args(&Args::from_env(NumericMode::FLAGS)?)?;
+    let file = File::open(env::current_dir()?.join("src/input.txt"))?;
+    let reader = BufReader::new(file);
+
+    let mut matrix: Vec<Vec<u8>> = Vec::new();
+
+    for line in reader.lines() {
+        matrix.push(line?.as_bytes().to_vec());
+    }
+
+    let (s_line, s_col) = find_start(&matrix);
+    let timeline = propagate_quantum_tachyon(&matrix, s_line, s_col, mode)?;
+    print!("total timeline: {timeline}");
+
+    Ok(())
+}
@@ -1,19 +1,15 @@
-use std::{
-    collections::HashSet,
-    env,
-    fs::File,
-    io::{self, BufRead, BufReader},
-};
+use common::numeric::{Num, NumericMode, OverflowError};
+use std::{collections::HashSet, io};
 
 #[derive(PartialEq, Eq, PartialOrd, Ord, Debug, Clone)]
-struct Pos {
-    x: i64,
-    y: i64,
-    z: i64,
+pub struct Pos {
+    pub x: i64,
+    pub y: i64,
+    pub z: i64,
 }
 
 impl Pos {
-    fn from(val: Vec<&str>) -> io::Result<Pos> {
+    pub fn from(val: Vec<&str>) -> io::Result<Pos> {
         Ok(Pos {
             x: val[0].parse().map_err(|err| {
                 io::Error::new(
@@ -38,23 +34,36 @@ impl Pos {
 }
 
 // we only need to compare distances, not the real square root
-fn distance(lval: &Pos, rval: &Pos) -> i64 {
-    return (lval.x - rval.x) * (lval.x - rval.x)
-        + (lval.y - rval.y) * (lval.y - rval.y)
-        + (lval.z - rval.z) * (lval.z - rval.z);
+fn distance(lval: &Pos, rval: &Pos, mode: NumericMode) -> Result<Num, OverflowError> {
+    let mut result = Num::new(mode, 0);
+    for (l, r) in [(lval.x, rval.x), (lval.y, rval.y), (lval.z, rval.z)] {
+        let diff = Num::new(mode, l).try_sub(&Num::new(mode, r))?;
+        result = result.try_add(&diff.try_mul(&diff)?)?;
+    }
+    Ok(result)
 }
 
-fn create_circuit(juctions: &Vec<Pos>, connection_limit: usize) -> (usize, i64) {
+pub fn create_circuit(
+    juctions: &Vec<Pos>,
+    connection_limit: usize,
+    mode: NumericMode,
+) -> io::Result<(Num, Num)> {
     let mut distances = Vec::new();
 
     for lidx in 0..juctions.len() {
         for ridx in (lidx + 1)..juctions.len() {
-            distances.push((lidx, ridx, distance(&juctions[lidx], &juctions[ridx])));
+            let dist = distance(&juctions[lidx], &juctions[ridx], mode).map_err(|err| {
+                io::Error::new(
+                    io::ErrorKind::InvalidData,
+                    format!("distance between {lidx} and {ridx}: {err}"),
+                )
+            })?;
+            distances.push((lidx, ridx, dist));
         }
     }
     distances.sort_by(|lv, rv| lv.2.cmp(&rv.2));
 
-    let mut distance_to_wall = 0;
+    let mut distance_to_wall = Num::new(mode, 0);
     let mut connection_done = 0;
     let mut circuit: Vec<HashSet<usize>> = Vec::new();
     let mut associated: HashSet<usize> = HashSet::new();
@@ -105,7 +114,14 @@ fn create_circuit(juctions: &Vec<Pos>, connection_limit: usize) -> (usize, i64)
         }
 
         if circuit.len() == 1 && associated.len() == juctions.len() {
-            distance_to_wall = juctions[dis.0].x * juctions[dis.1].x;
+            distance_to_wall = Num::new(mode, juctions[dis.0].x)
+                .try_mul(&Num::new(mode, juctions[dis.1].x))
+                .map_err(|err| {
+                    io::Error::new(
+                        io::ErrorKind::InvalidData,
+                        format!("distance to wall: {err}"),
+                    )
+                })?;
             break;
         }
     }
@@ -124,18 +140,41 @@ fn create_circuit(juctions: &Vec<Pos>, connection_limit: usize) -> (usize, i64)
         }
     }
 
-    let res = largest_circuit.iter().product();
+    let mut res = Num::new(mode, 1);
+    for size in largest_circuit {
+        res = res.try_mul(&Num::new(mode, size as i64)).map_err(|err| {
+            io::Error::new(io::ErrorKind::InvalidData, format!("network size: {err}"))
+        })?;
+    }
 
-    (res, distance_to_wall)
+    Ok((res, distance_to_wall))
 }
 
 #[test]
 fn test_distance() {
-    let result = distance(&Pos { x: 1, y: 1, z: 1 }, &Pos { x: 2, y: 1, z: 1 });
-    assert_eq!(result, 1);
+    let result = distance(
+        &Pos { x: 1, y: 1, z: 1 },
+        &Pos { x: 2, y: 1, z: 1 },
+        NumericMode::Checked,
+    );
+    assert_eq!(result.unwrap(), 1);
+
+    let result = distance(
+        &Pos { x: 2, y: 1, z: 1 },
+        &Pos { x: 1, y: 1, z: 1 },
+        NumericMode::Checked,
+    );
+    assert_eq!(result.unwrap(), 1);
 
-    let result = distance(&Pos { x: 2, y: 1, z: 1 }, &Pos { x: 1, y: 1, z: 1 });
-    assert_eq!(result, 1);
+    let far = Pos {
+        x: 4_000_000_000,
+        y: 0,
+        z: 0,
+    };
+    let origin = Pos { x: 0, y: 0, z: 0 };
+    assert!(distance(&far, &origin, NumericMode::Checked).is_err());
+    let result = distance(&far, &origin, NumericMode::Big);
+    assert_eq!(result.unwrap().to_string(), "16000000000000000000");
 }
 
 #[test]
@@ -250,24 +289,14 @@ fn test_create_circuit() {
         },
     ];
 
-    let (res, _) = create_circuit(&juctions, 10);
+    let (res, _) = create_circuit(&juctions, 10, NumericMode::Checked).unwrap();
     assert_eq!(res, 40);
 
-    let (_, wall) = create_circuit(&juctions, 0);
+    let (_, wall) = create_circuit(&juctions, 0, NumericMode::Checked).unwrap();
     assert_eq!(wall, 25272);
-}
 
-fn main() -> io::Result<()> {
-    let file = File::open(env::current_dir()?.join("src/input.txt"))?;
-    let reader = BufReader::new(file);
-
-    let mut juctions = Vec::new();
-
-    for li in reader.lines() {
-        juctions.push(Pos::from(li?.splitn(3, ',').collect())?);
-    }
-
-    let (res, wall) = create_circuit(&juctions, 0);
-    println!("juction network size: {res}, distance to wall: {wall}");
-    Ok(())
+    let (res, _) = create_circuit(&juctions, 10, NumericMode::Big).unwrap();
+    assert_eq!(res, 40);
+    let (_, wall) = create_circuit(&juctions, 0, NumericMode::Big).unwrap();
+    assert_eq!(wall, 25272);
 }
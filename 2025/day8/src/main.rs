@@ -0,0 +1,24 @@
+use aoc2025_day8::{Pos, create_circuit};
+use common::args::Args;
+use common::numeric::NumericMode;
+use std::{
+    env,
+    fs::File,
+    io::{self, BufRead, BufReader},
+};
+
+fn main() -> io::Result<()> {
+    let mode = NumericMode::from_args(&Args::from_env(NumericMode::FLAGS)?)?;
+    let file = File::open(env::current_dir()?.join("src/input.txt"))?;
+    let reader = BufReader::new(file);
+
+    let mut juctions = Vec::new();
+
+    for li in reader.lines() {
+        juctions.push(Pos::from(li?.splitn(3, ',').collect())?);
+    }
+
+    let (res, wall) = create_circuit(&juctions, 0, mode)?;
+    println!("juction network size: {res}, distance to wall: {wall}");
+    Ok(())
+}
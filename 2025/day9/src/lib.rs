@@ -1,18 +1,17 @@
+use common::numeric::{Num, NumericMode, OverflowError};
 use std::{
     cmp::{max, min},
-    env,
-    fs::File,
     i64,
-    io::{self, BufRead, BufReader}, vec,
+    io,
 };
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
-struct Position {
-    x: i64,
-    y: i64,
+pub struct Position {
+    pub x: i64,
+    pub y: i64,
 }
 
-fn parse_line(line: &String) -> io::Result<Position> {
+pub fn parse_line(line: &String) -> io::Result<Position> {
     match line.split_once(',') {
         Some(pair) => Ok(Position {
             x: pair.0.parse().map_err(|err| {
@@ -35,46 +34,72 @@ fn parse_line(line: &String) -> io::Result<Position> {
     }
 }
 
-fn size(lval: &Position, rval: &Position) -> i64 {
-    let x_s = (lval.x - rval.x).abs() + 1;
-    let y_s = (lval.y - rval.y).abs() + 1;
+pub fn size(lval: &Position, rval: &Position, mode: NumericMode) -> Result<Num, OverflowError> {
+    let one = Num::new(mode, 1);
+    let x_s = Num::new(mode, lval.x)
+        .try_sub(&Num::new(mode, rval.x))?
+        .try_abs()?
+        .try_add(&one)?;
+    let y_s = Num::new(mode, lval.y)
+        .try_sub(&Num::new(mode, rval.y))?
+        .try_abs()?
+        .try_add(&one)?;
+
+    x_s.try_mul(&y_s)
+}
 
-    return x_s * y_s;
+#[cfg(test)]
+fn checked_size(lval: &Position, rval: &Position) -> Num {
+    size(lval, rval, NumericMode::Checked).unwrap()
 }
 
 #[test]
 fn test_size() {
     assert_eq!(
-        size(&Position { x: 7, y: 3 }, &Position { x: 11, y: 1 }),
+        checked_size(&Position { x: 7, y: 3 }, &Position { x: 11, y: 1 }),
         15
     );
-    assert_eq!(size(&Position { x: 9, y: 7 }, &Position { x: 9, y: 5 }), 3);
-    assert_eq!(size(&Position { x: 9, y: 5 }, &Position { x: 2, y: 3 }), 24);
+    assert_eq!(checked_size(&Position { x: 9, y: 7 }, &Position { x: 9, y: 5 }), 3);
+    assert_eq!(checked_size(&Position { x: 9, y: 5 }, &Position { x: 2, y: 3 }), 24);
     assert_eq!(
-        size(&Position { x: 2, y: 5 }, &Position { x: 11, y: 1 }),
+        checked_size(&Position { x: 2, y: 5 }, &Position { x: 11, y: 1 }),
         50
     );
-    assert_eq!(size(&Position { x: 7, y: 3 }, &Position { x: 2, y: 3 }), 6);
+    assert_eq!(checked_size(&Position { x: 7, y: 3 }, &Position { x: 2, y: 3 }), 6);
     assert_eq!(
-        size(&Position { x: 7, y: 1 }, &Position { x: 11, y: 7 }),
+        checked_size(&Position { x: 7, y: 1 }, &Position { x: 11, y: 7 }),
         35
     );
+
+    let far = Position {
+        x: 5_000_000_000,
+        y: 5_000_000_000,
+    };
+    let origin = Position { x: 0, y: 0 };
+    assert!(size(&far, &origin, NumericMode::Checked).is_err());
+    assert_eq!(
+        size(&far, &origin, NumericMode::Big).unwrap().to_string(),
+        "25000000010000000001"
+    );
 }
 
-fn best_rectangle(positions: &Vec<Position>) -> (Option<(Position, Position)>, i64) {
+pub fn best_rectangle(
+    positions: &Vec<Position>,
+    mode: NumericMode,
+) -> Result<(Option<(Position, Position)>, Num), OverflowError> {
     let mut best_pair = None;
-    let mut best_size = 0;
+    let mut best_size = Num::new(mode, 0);
 
     for l_idx in 0..positions.len() {
         for r_idx in l_idx + 1..positions.len() {
-            let size = size(&positions[l_idx], &positions[r_idx]);
+            let size = size(&positions[l_idx], &positions[r_idx], mode)?;
             if size > best_size {
                 best_size = size;
                 best_pair = Some((positions[l_idx], positions[r_idx]))
             }
         }
     }
-    (best_pair, best_size)
+    Ok((best_pair, best_size))
 }
 
 #[test]
@@ -90,7 +115,7 @@ fn test_best_rectangle() {
         Position { x: 7, y: 3 },
     ];
 
-    let (index, size) = best_rectangle(&input);
+    let (index, size) = best_rectangle(&input, NumericMode::Checked).unwrap();
     assert!(index.is_some());
     assert_eq!(size, 50);
 }
@@ -305,15 +330,21 @@ fn print_mat(mat: &Vec<Vec<u8>>) {
     }
 }
 
-fn best_rectangle_part2(vertices: &Vec<Position>) -> (Option<(&Position, &Position)>, i64) {
+// the corners of the best rectangle, None without any, and its size
+pub type Rectangle<'a> = (Option<(&'a Position, &'a Position)>, Num);
+
+pub fn best_rectangle_part2(
+    vertices: &Vec<Position>,
+    mode: NumericMode,
+) -> Result<Rectangle<'_>, OverflowError> {
     let vertices_size = vertices.len();
     let mut best_pair = None;
-    let mut best_size = 0;
+    let mut best_size = Num::new(mode, 0);
 
     let mut min_x = i64::MAX;
     let mut min_y = i64::MAX;
-    let mut max_x = 0;
-    let mut max_y = 0;
+    let mut max_x = i64::MIN;
+    let mut max_y = i64::MIN;
     for vert in vertices {
         min_x = min(vert.x, min_x);
         min_y = min(vert.y, min_y);
@@ -321,7 +352,6 @@ fn best_rectangle_part2(vertices: &Vec<Position>) -> (Option<(&Position, &Positi
         max_y = max(vert.y, max_y);
     }
 
-    println!("allocate_poly");
     let mut mat = Vec::new();
     let mat_len = (max_y - min_y + 1 + 1) as usize;
     let line_len = (max_x - min_x + 1 + 1) as usize;
@@ -332,7 +362,6 @@ fn best_rectangle_part2(vertices: &Vec<Position>) -> (Option<(&Position, &Positi
         mat.push(line);
     }
 
-    println!("draw_poly");
     // fill matrix
     for lidx in 0..vertices_size {
         let x1 = (vertices[lidx].x - min_x) as usize;
@@ -351,24 +380,19 @@ fn best_rectangle_part2(vertices: &Vec<Position>) -> (Option<(&Position, &Positi
         }
     }
 
-    println!("fill_poly");
     mat = fill_poly(mat);
-    // print_mat(&mat);
 
     // try poly
-    println!("try polys");
     for lidx in 0..vertices_size {
-        println!("{}/{}", lidx, vertices_size);
-
         for ridx in (lidx+1)..vertices_size {
-            let s = size(&vertices[lidx], &vertices[ridx]);
+            let s = size(&vertices[lidx], &vertices[ridx], mode)?;
             if s < best_size {
                 continue;
             }
-            let xs = (min(vertices[lidx].x, vertices[ridx].x));        
-            let xe = (max(vertices[lidx].x, vertices[ridx].x));        
-            let ys = (min(vertices[lidx].y, vertices[ridx].y));        
-            let ye = (max(vertices[lidx].y, vertices[ridx].y));
+            let xs = min(vertices[lidx].x, vertices[ridx].x);        
+            let xe = max(vertices[lidx].x, vertices[ridx].x);        
+            let ys = min(vertices[lidx].y, vertices[ridx].y);        
+            let ye = max(vertices[lidx].y, vertices[ridx].y);
 
             let inter = inner_intersect_polygon(&vertices, xs, xe, ys, ye);
             if inter {
@@ -386,12 +410,11 @@ fn best_rectangle_part2(vertices: &Vec<Position>) -> (Option<(&Position, &Positi
             }
             best_size = s;
             best_pair = Some((&vertices[lidx], &vertices[ridx]));
-            println!("found rectangle {} -> {:?}", s, (vertices[lidx], vertices[ridx]));
         }
     }
 
     // check far idx
-    (best_pair, best_size)
+    Ok((best_pair, best_size))
 }
 
 fn go_outsize(mat: &Vec<Vec<u8>>, xs: usize, xe: usize, ys: usize, ye: usize) -> bool {
@@ -427,18 +450,29 @@ fn test_best_rectangle_part2() {
         Position { x: 7, y: 3 },
     ];
 
-    let (index, size) = best_rectangle_part2(&input);
+    let (index, size) = best_rectangle_part2(&input, NumericMode::Checked).unwrap();
     assert!(index.is_some());
     assert_eq!(size, 24);
     let (l, r) = index.unwrap();
-    println!("pos {:?}, {:?}", l, r);
+    assert_eq!((*l, *r), (Position { x: 9, y: 5 }, Position { x: 2, y: 3 }));
+
+    // the grid only covers the bounding box, far from the origin on the negative side too
+    let far: Vec<Position> = input
+        .iter()
+        .map(|p| Position {
+            x: p.x - 1_000_000_000,
+            y: p.y - 1_000_000_000,
+        })
+        .collect();
+    let (_, size) = best_rectangle_part2(&far, NumericMode::Checked).unwrap();
+    assert_eq!(size, 24);
 }
 
-fn print_polygon(vertices: &Vec<Position>, dezoom: i64) {
+pub fn draw_polygon(vertices: &Vec<Position>, dezoom: i64) -> Vec<Vec<u8>> {
     let mut min_x = i64::MAX;
     let mut min_y = i64::MAX;
-    let mut max_x = 0;
-    let mut max_y = 0;
+    let mut max_x = i64::MIN;
+    let mut max_y = i64::MIN;
     for vert in vertices {
         min_x = min(vert.x, min_x);
         min_y = min(vert.y, min_y);
@@ -463,35 +497,38 @@ fn print_polygon(vertices: &Vec<Position>, dezoom: i64) {
         mat[y][x] = b'X';
     }
 
-    for l in mat {
-        println!("{}", str::from_utf8(&l).unwrap());
-    }
+    mat
 }
 
-fn main() -> io::Result<()> {
-    let file = File::open(env::current_dir()?.join("src/input.txt"))?;
-    let reader = BufReader::new(file);
-
-    let mut positions: Vec<Position> = Vec::new();
-
-    for l in reader.lines() {
-        let pos = parse_line(&l?)?;
-        positions.push(pos);
-    }
-
-    // print_polygon(&positions, 1000);
+#[test]
+fn test_draw_polygon() {
+    let input = vec![
+        Position { x: 7, y: 1 },
+        Position { x: 11, y: 1 },
+        Position { x: 11, y: 7 },
+        Position { x: 9, y: 7 },
+        Position { x: 9, y: 5 },
+        Position { x: 2, y: 5 },
+        Position { x: 2, y: 3 },
+        Position { x: 7, y: 3 },
+    ];
 
-    let (best_pair, best_size) = best_rectangle_part2(&positions);
+    let mat = draw_polygon(&input, 1);
+    assert_eq!(mat.len(), 7);
+    assert_eq!(mat[0], b".....X...X".to_vec());
+    assert_eq!(mat[6], b".......X.X".to_vec());
 
-    match best_pair {
-        Some(pair) => println!("the best size: {best_size}, pair: {pair:?}"),
-        None => {
-            return Err(io::Error::new(
-                io::ErrorKind::InvalidInput,
-                "failed to find the best pair",
-            ));
-        }
-    };
+    let mat = draw_polygon(&input, 2);
+    assert_eq!(mat.len(), 4);
+    assert_eq!(mat[0].len(), 5);
+    assert_eq!(render_polygon(&input, 2).lines().count(), 4);
+}
 
-    Ok(())
+// the drawing of draw_polygon, one line per row
+pub fn render_polygon(vertices: &Vec<Position>, dezoom: i64) -> String {
+    draw_polygon(vertices, dezoom)
+        .into_iter()
+        .map(|l| String::from_utf8(l).unwrap())
+        .collect::<Vec<_>>()
+        .join("\n")
 }
@@ -0,0 +1,39 @@
+use aoc2025_day9::{Position, best_rectangle_part2, parse_line};
+use common::args::Args;
+use common::numeric::NumericMode;
+use std::{
+    env,
+    fs::File,
+    io::{self, BufRead, BufReader},
+};
+
+fn main() -> io::Result<()> {
+    let mode = NumericMode::from_args(&Args::from_env(NumericMode::FLAGS)?)?;
+    let file = File::open(env::current_dir()?.join("src/input.txt"))?;
+    let reader = BufReader::new(file);
+
+    let mut positions: Vec<Position> = Vec::new();
+
+    for l in reader.lines() {
+        let pos = parse_line(&l?)?;
+        positions.push(pos);
+    }
+
+    // println!("{}", render_polygon(&positions, 1000));
+
+    let (best_pair, best_size) = best_rectangle_part2(&positions, mode).map_err(|err| {
+        io::Error::new(io::ErrorKind::InvalidData, format!("rectangle size: {err}"))
+    })?;
+
+    match best_pair {
+        Some(pair) => println!("the best size: {best_size}, pair: {pair:?}"),
+        None => {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                "failed to find the best pair",
+            ));
+        }
+    };
+
+    Ok(())
+}
@@ -0,0 +1,189 @@
+use std::{env, fmt, io, str::FromStr};
+
+fn invalid_input(msg: String) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidInput, msg)
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Arity {
+    // `--flag`
+    Switch,
+    // `--flag <value>` or `--flag=<value>`
+    Value,
+    // `--flag` or `--flag=<value>`, the value cannot be a separate argument
+    OptionalValue,
+}
+
+// a command line flag, the name includes the leading dashes
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Flag {
+    pub name: &'static str,
+    pub arity: Arity,
+}
+
+impl Flag {
+    pub const fn switch(name: &'static str) -> Flag {
+        Flag {
+            name,
+            arity: Arity::Switch,
+        }
+    }
+
+    pub const fn value(name: &'static str) -> Flag {
+        Flag {
+            name,
+            arity: Arity::Value,
+        }
+    }
+
+    pub const fn optional_value(name: &'static str) -> Flag {
+        Flag {
+            name,
+            arity: Arity::OptionalValue,
+        }
+    }
+}
+
+// a command line checked against every flag a binary knows, each option then reads its
+// own flags from it. anything else is an error so a misspelled flag is not dropped silently
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Args {
+    // the given flags in order, with their value when they have one
+    flags: Vec<(&'static str, Option<String>)>,
+}
+
+impl Args {
+    // `args` without the program name
+    pub fn parse<I: IntoIterator<Item = String>>(args: I, known: &[Flag]) -> io::Result<Args> {
+        let mut flags = Vec::new();
+        let mut args = args.into_iter();
+        while let Some(arg) = args.next() {
+            let (name, value) = match arg.split_once('=') {
+                Some((name, value)) => (name, Some(value.to_string())),
+                None => (arg.as_str(), None),
+            };
+            let Some(flag) = known.iter().find(|flag| flag.name == name) else {
+                let names: Vec<&str> = known.iter().map(|flag| flag.name).collect();
+                return Err(invalid_input(if names.is_empty() {
+                    format!("unknown argument '{arg}', no argument expected")
+                } else {
+                    format!(
+                        "unknown argument '{arg}', expected one of {}",
+                        names.join(", ")
+                    )
+                }));
+            };
+            let value = match (flag.arity, value) {
+                (Arity::Switch, Some(_)) => {
+                    return Err(invalid_input(format!("{name} does not take a value")));
+                }
+                (Arity::Value, None) => Some(
+                    args.next()
+                        .ok_or_else(|| invalid_input(format!("missing value after {name}")))?,
+                ),
+                (_, value) => value,
+            };
+            flags.push((flag.name, value));
+        }
+        Ok(Args { flags })
+    }
+
+    // the arguments of this process
+    pub fn from_env(known: &[Flag]) -> io::Result<Args> {
+        Args::parse(env::args().skip(1), known)
+    }
+
+    pub fn has(&self, name: &str) -> bool {
+        self.flags.iter().any(|(flag, _)| *flag == name)
+    }
+
+    // the value of every occurrence of the flag, in order, None for a bare optional value flag
+    pub fn all(&self, name: &str) -> Vec<Option<&str>> {
+        self.flags
+            .iter()
+            .filter(|(flag, _)| *flag == name)
+            .map(|(_, value)| value.as_deref())
+            .collect()
+    }
+
+    // the values given to the flag, in order
+    pub fn values(&self, name: &str) -> Vec<&str> {
+        self.all(name).into_iter().flatten().collect()
+    }
+
+    // the last value given to the flag
+    pub fn value(&self, name: &str) -> Option<&str> {
+        self.values(name).pop()
+    }
+
+    // the last occurrence wins: None when the flag is absent, Some(None) when it has no value
+    pub fn last(&self, name: &str) -> Option<Option<&str>> {
+        self.all(name).pop()
+    }
+
+    // the last value parsed, None when the flag is absent
+    pub fn parse_value<T: FromStr>(&self, name: &str) -> io::Result<Option<T>>
+    where
+        T::Err: fmt::Display,
+    {
+        self.value(name)
+            .map(|value| {
+                value
+                    .trim()
+                    .parse()
+                    .map_err(|err| invalid_input(format!("invalid {name} value '{value}': {err}")))
+            })
+            .transpose()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const FLAGS: &[Flag] = &[
+        Flag::value("--size"),
+        Flag::switch("--carry"),
+        Flag::optional_value("--trace"),
+    ];
+
+    fn parse(a: &[&str]) -> io::Result<Args> {
+        Args::parse(a.iter().map(|s| s.to_string()), FLAGS)
+    }
+
+    #[test]
+    fn test_parse() {
+        let args = parse(&["--size", "10", "--trace", "--carry", "--size=20"]).unwrap();
+        assert_eq!(args.values("--size"), ["10", "20"]);
+        assert_eq!(args.value("--size"), Some("20"));
+        assert_eq!(args.parse_value::<i32>("--size").unwrap(), Some(20));
+        assert_eq!(args.last("--trace"), Some(None));
+        assert!(args.has("--carry"));
+
+        let args = parse(&["--trace=csv"]).unwrap();
+        assert_eq!(args.last("--trace"), Some(Some("csv")));
+        assert_eq!(args.last("--size"), None);
+        assert_eq!(args.parse_value::<i32>("--size").unwrap(), None);
+        assert!(!args.has("--carry"));
+        // a value flag takes the next argument whatever it looks like
+        assert_eq!(parse(&["--size", "-5"]).unwrap().values("--size"), ["-5"]);
+        assert_eq!(parse(&[]).unwrap(), Args::default());
+    }
+
+    #[test]
+    fn test_errors() {
+        assert!(parse(&["--sise", "10"]).is_err());
+        assert!(parse(&["day1"]).is_err());
+        assert!(parse(&["--size"]).is_err());
+        assert!(parse(&["--carry=yes"]).is_err());
+        // a value of an optional value flag is only written with =
+        assert!(parse(&["--trace", "csv"]).is_err());
+        assert!(
+            parse(&["--size=x"])
+                .unwrap()
+                .parse_value::<i32>("--size")
+                .is_err()
+        );
+        assert!(Args::parse(["--size".to_string()], &[]).is_err());
+    }
+}
@@ -0,0 +1,276 @@
+use std::{cmp::Ordering, fmt, io, str::FromStr};
+
+use crate::args::{Args, Flag};
+use num_bigint::BigInt;
+
+// how the days are doing their counts and products:
+// - checked: 64 bits integers, any overflow is reported as an error
+// - big: arbitrary precision, never overflow
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum NumericMode {
+    #[default]
+    Checked,
+    Big,
+}
+
+impl FromStr for NumericMode {
+    type Err = io::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "checked" => Ok(NumericMode::Checked),
+            "big" => Ok(NumericMode::Big),
+            _ => Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                format!("unknown numeric mode '{s}', expected 'checked' or 'big'"),
+            )),
+        }
+    }
+}
+
+impl NumericMode {
+    pub const FLAGS: &'static [Flag] = &[Flag::value("--numeric")];
+
+    // `--numeric <mode>` or `--numeric=<mode>`, checked without it
+    pub fn from_args(args: &Args) -> io::Result<NumericMode> {
+        args.value("--numeric")
+            .map_or(Ok(NumericMode::default()), str::parse)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OverflowError {
+    op: &'static str,
+    lhs: i64,
+    rhs: i64,
+}
+
+impl fmt::Display for OverflowError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "overflow computing {} {} {} (try --numeric big)",
+            self.lhs, self.op, self.rhs
+        )
+    }
+}
+
+impl std::error::Error for OverflowError {}
+
+impl From<OverflowError> for io::Error {
+    fn from(err: OverflowError) -> Self {
+        io::Error::new(io::ErrorKind::InvalidData, err)
+    }
+}
+
+#[derive(Debug, Clone)]
+pub enum Num {
+    Fixed(i64),
+    Big(BigInt),
+}
+
+impl Num {
+    pub fn new(mode: NumericMode, value: i64) -> Num {
+        match mode {
+            NumericMode::Checked => Num::Fixed(value),
+            NumericMode::Big => Num::Big(BigInt::from(value)),
+        }
+    }
+
+    pub fn parse(mode: NumericMode, value: &str) -> io::Result<Num> {
+        let invalid = |err: &dyn fmt::Display| {
+            io::Error::new(
+                io::ErrorKind::InvalidInput,
+                format!("failed to parse {value} into number: {err}"),
+            )
+        };
+        match mode {
+            NumericMode::Checked => Ok(Num::Fixed(value.parse().map_err(|e| invalid(&e))?)),
+            NumericMode::Big => Ok(Num::Big(value.parse().map_err(|e| invalid(&e))?)),
+        }
+    }
+
+    pub fn is_zero(&self) -> bool {
+        *self == 0
+    }
+
+    pub fn try_add(&self, rhs: &Num) -> Result<Num, OverflowError> {
+        match (self, rhs) {
+            (Num::Fixed(l), Num::Fixed(r)) => {
+                l.checked_add(*r).map(Num::Fixed).ok_or(OverflowError {
+                    op: "+",
+                    lhs: *l,
+                    rhs: *r,
+                })
+            }
+            _ => Ok(Num::Big(self.to_big() + rhs.to_big())),
+        }
+    }
+
+    pub fn try_sub(&self, rhs: &Num) -> Result<Num, OverflowError> {
+        match (self, rhs) {
+            (Num::Fixed(l), Num::Fixed(r)) => {
+                l.checked_sub(*r).map(Num::Fixed).ok_or(OverflowError {
+                    op: "-",
+                    lhs: *l,
+                    rhs: *r,
+                })
+            }
+            _ => Ok(Num::Big(self.to_big() - rhs.to_big())),
+        }
+    }
+
+    pub fn try_mul(&self, rhs: &Num) -> Result<Num, OverflowError> {
+        match (self, rhs) {
+            (Num::Fixed(l), Num::Fixed(r)) => {
+                l.checked_mul(*r).map(Num::Fixed).ok_or(OverflowError {
+                    op: "*",
+                    lhs: *l,
+                    rhs: *r,
+                })
+            }
+            _ => Ok(Num::Big(self.to_big() * rhs.to_big())),
+        }
+    }
+
+    pub fn try_abs(&self) -> Result<Num, OverflowError> {
+        match self {
+            Num::Fixed(v) => v.checked_abs().map(Num::Fixed).ok_or(OverflowError {
+                op: "abs",
+                lhs: *v,
+                rhs: 0,
+            }),
+            Num::Big(v) => Ok(Num::Big(v.magnitude().clone().into())),
+        }
+    }
+
+    fn to_big(&self) -> BigInt {
+        match self {
+            Num::Fixed(v) => BigInt::from(*v),
+            Num::Big(v) => v.clone(),
+        }
+    }
+}
+
+impl From<Num> for BigInt {
+    fn from(value: Num) -> Self {
+        match value {
+            Num::Fixed(v) => BigInt::from(v),
+            Num::Big(v) => v,
+        }
+    }
+}
+
+impl PartialEq for Num {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for Num {}
+
+impl PartialEq<i64> for Num {
+    fn eq(&self, other: &i64) -> bool {
+        match self {
+            Num::Fixed(v) => v == other,
+            Num::Big(v) => *v == BigInt::from(*other),
+        }
+    }
+}
+
+impl PartialOrd for Num {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Num {
+    fn cmp(&self, other: &Self) -> Ordering {
+        match (self, other) {
+            (Num::Fixed(l), Num::Fixed(r)) => l.cmp(r),
+            _ => self.to_big().cmp(&other.to_big()),
+        }
+    }
+}
+
+impl fmt::Display for Num {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Num::Fixed(v) => write!(f, "{v}"),
+            Num::Big(v) => write!(f, "{v}"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_mode_from_args() {
+        let args = |list: &[&str]| {
+            Args::parse(list.iter().map(|s| s.to_string()), NumericMode::FLAGS)
+                .and_then(|args| NumericMode::from_args(&args))
+        };
+        assert_eq!(args(&[]).unwrap(), NumericMode::Checked);
+        assert_eq!(args(&["--numeric", "big"]).unwrap(), NumericMode::Big);
+        assert_eq!(args(&["--numeric=checked"]).unwrap(), NumericMode::Checked);
+        assert!(args(&["--numeric"]).is_err());
+        assert!(args(&["--numeric=float"]).is_err());
+        assert!(args(&["--numerc", "big"]).is_err());
+    }
+
+    #[test]
+    fn test_checked_overflow() {
+        let max = Num::new(NumericMode::Checked, i64::MAX);
+        let one = Num::new(NumericMode::Checked, 1);
+        let two = Num::new(NumericMode::Checked, 2);
+        assert!(max.try_add(&one).is_err());
+        assert!(max.try_mul(&two).is_err());
+        assert!(
+            Num::new(NumericMode::Checked, i64::MIN)
+                .try_sub(&one)
+                .is_err()
+        );
+        assert!(Num::new(NumericMode::Checked, i64::MIN).try_abs().is_err());
+        assert_eq!(max.try_sub(&one).unwrap(), i64::MAX - 1);
+
+        let err = max.try_mul(&two).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            format!("overflow computing {} * 2 (try --numeric big)", i64::MAX)
+        );
+    }
+
+    #[test]
+    fn test_big() {
+        let max = Num::new(NumericMode::Big, i64::MAX);
+        let square = max.try_mul(&max).unwrap();
+        assert_eq!(square.to_string(), "85070591730234615847396907784232501249");
+        assert!(square > max);
+        assert_eq!(Num::new(NumericMode::Big, -5).try_abs().unwrap(), 5);
+
+        // mixing both modes promotes to big
+        let mixed = Num::new(NumericMode::Checked, i64::MAX)
+            .try_add(&Num::new(NumericMode::Big, 1))
+            .unwrap();
+        assert_eq!(mixed.to_string(), "9223372036854775808");
+        assert_eq!(
+            Num::new(NumericMode::Checked, 3),
+            Num::new(NumericMode::Big, 3)
+        );
+    }
+
+    #[test]
+    fn test_parse() {
+        assert_eq!(Num::parse(NumericMode::Checked, "42").unwrap(), 42);
+        assert!(Num::parse(NumericMode::Checked, "99999999999999999999").is_err());
+        assert_eq!(
+            Num::parse(NumericMode::Big, "99999999999999999999")
+                .unwrap()
+                .to_string(),
+            "99999999999999999999"
+        );
+        assert!(Num::parse(NumericMode::Big, "abc").is_err());
+    }
+}
@@ -0,0 +1,330 @@
+use pyo3::{exceptions::PyValueError, prelude::*};
+use std::io;
+
+fn to_py_err(err: io::Error) -> PyErr {
+    PyValueError::new_err(err.to_string())
+}
+
+fn to_matrix(lines: Vec<String>) -> Vec<Vec<u8>> {
+    lines.into_iter().map(String::into_bytes).collect()
+}
+
+fn from_matrix(matrix: Vec<Vec<u8>>) -> PyResult<Vec<String>> {
+    matrix
+        .into_iter()
+        .map(|line| String::from_utf8(line).map_err(|err| PyValueError::new_err(err.to_string())))
+        .collect()
+}
+
+// python ints are unbounded, so every day runs in big numeric mode
+#[pymodule]
+mod aoc {
+    use pyo3::prelude::*;
+
+    // one submodule per year, then per day: aoc.y2025.day1
+    #[pymodule]
+    mod y2025 {
+        use pyo3::prelude::*;
+
+        #[pymodule]
+        mod day1 {
+            use common::numeric::NumericMode;
+            use num_bigint::BigInt;
+            use pyo3::{exceptions::PyValueError, prelude::*};
+
+            #[pyfunction]
+            fn parse_instruction(token: &str) -> PyResult<i64> {
+                ::day1::parse_instruction(token).map_err(crate::to_py_err)
+            }
+
+            #[pyfunction]
+            #[pyo3(signature = (line, line_number = 1))]
+            fn parse_line(line: &str, line_number: usize) -> PyResult<Vec<i64>> {
+                ::day1::parse_line(line, line_number).map_err(crate::to_py_err)
+            }
+
+            #[pyfunction]
+            fn roll_value(current: i32, val: i64) -> PyResult<(i32, i64)> {
+                if !(0..100).contains(&current) {
+                    return Err(PyValueError::new_err(format!(
+                        "cannot turn the dial from {current} by {val}"
+                    )));
+                }
+                ::day1::roll_value(current, val).map_err(crate::to_py_err)
+            }
+
+            #[pyfunction]
+            #[pyo3(signature = (rotations, start = 50, size = 100, targets = vec![0]))]
+            fn count_zeros(
+                rotations: Vec<i64>,
+                start: i32,
+                size: i32,
+                targets: Vec<i32>,
+            ) -> PyResult<(BigInt, BigInt)> {
+                let dial = ::day1::Dial::new(size, start, targets).map_err(crate::to_py_err)?;
+                let mut counter = dial.counter(NumericMode::Big);
+                for rotation in rotations {
+                    counter.roll(rotation).map_err(crate::to_py_err)?;
+                }
+                Ok((counter.landed.into(), counter.clicks.into()))
+            }
+        }
+
+        #[pymodule]
+        mod day2 {
+            use num_bigint::BigUint;
+            use pyo3::prelude::*;
+
+            #[pyfunction]
+            #[pyo3(signature = (id, rule = "2+"))]
+            fn detect_bad_id(id: &str, rule: &str) -> PyResult<bool> {
+                let rule = rule.parse().map_err(crate::to_py_err)?;
+                Ok(::day2::detect_bad_id_with(id, &rule))
+            }
+
+            #[pyfunction]
+            #[pyo3(signature = (input, base = 10))]
+            fn parse_ranges(input: &str, base: u32) -> PyResult<Vec<(u128, u128)>> {
+                let base = ::day2::check_base(base).map_err(crate::to_py_err)?;
+                ::day2::parse_ranges(input, base).map_err(crate::to_py_err)
+            }
+
+            #[pyfunction]
+            fn sum_bad_ids(begin: u64, end: u64) -> u128 {
+                ::day2::sum_bad_ids(begin, end)
+            }
+
+            #[pyfunction]
+            #[pyo3(signature = (begin, end, rule = "2+", base = 10))]
+            fn sum_repeated_ids(
+                begin: u128,
+                end: u128,
+                rule: &str,
+                base: u32,
+            ) -> PyResult<BigUint> {
+                let rule = rule.parse().map_err(crate::to_py_err)?;
+                let base = ::day2::check_base(base).map_err(crate::to_py_err)?;
+                Ok(::day2::sum_repeated_ids(begin, end, &rule, base))
+            }
+        }
+
+        #[pymodule]
+        mod day3 {
+            use num_bigint::BigUint;
+            use pyo3::prelude::*;
+
+            #[pyfunction]
+            fn compute_joltage(input: &str, size: usize) -> PyResult<BigUint> {
+                ::day3::compute_joltage(input, size).map_err(crate::to_py_err)
+            }
+        }
+
+        #[pymodule]
+        mod day4 {
+            use pyo3::prelude::*;
+
+            #[pyfunction]
+            fn process_floor(roll_map: Vec<String>) -> PyResult<(u64, Vec<String>)> {
+                let (count, processed) = ::day4::process_floor(&crate::to_matrix(roll_map));
+                Ok((count, crate::from_matrix(processed)?))
+            }
+
+            #[pyfunction]
+            fn remove_all_rolls(roll_map: Vec<String>) -> u64 {
+                ::day4::remove_all_rolls(crate::to_matrix(roll_map))
+            }
+        }
+
+        #[pymodule]
+        mod day5 {
+            use ::day5::RangeId;
+            use pyo3::{exceptions::PyValueError, prelude::*};
+
+            #[pyfunction]
+            fn parse_range(line: &str) -> PyResult<(u64, u64)> {
+                let range = line
+                    .parse::<RangeId>()
+                    .map_err(|err| PyValueError::new_err(err.badline))?;
+                Ok((range.min, range.max))
+            }
+
+            #[pyfunction]
+            fn in_range(range: (u64, u64), id: u64) -> bool {
+                ::day5::in_range(
+                    &RangeId {
+                        min: range.0,
+                        max: range.1,
+                    },
+                    id,
+                )
+            }
+
+            #[pyfunction]
+            fn consolidate_ranges(ranges: Vec<(u64, u64)>) -> Vec<(u64, u64)> {
+                let ids = ranges
+                    .into_iter()
+                    .map(|(min, max)| RangeId { min, max })
+                    .collect();
+                ::day5::consolidate_ranges(ids)
+                    .into_iter()
+                    .map(|range| (range.min, range.max))
+                    .collect()
+            }
+
+            // ranges, a blank line then ids: the count of fresh ingredients and of fresh ids
+            #[pyfunction]
+            fn solve(input: &str) -> PyResult<(u64, u64)> {
+                let mut lines = input.lines().map(str::trim);
+                let mut ranges = Vec::new();
+                for line in lines.by_ref().take_while(|line| !line.is_empty()) {
+                    let range = line
+                        .parse::<RangeId>()
+                        .map_err(|err| PyValueError::new_err(err.badline))?;
+                    ranges.push(range);
+                }
+                let ranges = ::day5::consolidate_ranges(ranges);
+
+                let mut fresh_ingredients = 0;
+                for line in lines.filter(|line| !line.is_empty()) {
+                    let id: u64 = line.parse().map_err(|err| {
+                        PyValueError::new_err(format!("failed to parse {line:?}: {err}"))
+                    })?;
+                    if ranges.iter().any(|range| ::day5::in_range(range, id)) {
+                        fresh_ingredients += 1;
+                    }
+                }
+                let fresh_ids = ranges.iter().map(|range| range.max - range.min + 1).sum();
+                Ok((fresh_ingredients, fresh_ids))
+            }
+        }
+
+        #[pymodule]
+        mod day6 {
+            use common::numeric::NumericMode;
+            use num_bigint::BigInt;
+            use pyo3::prelude::*;
+
+            #[pyfunction]
+            fn grand_total(lines: Vec<String>) -> PyResult<BigInt> {
+                let result = ::day6::grand_total(&crate::to_matrix(lines), NumericMode::Big)
+                    .map_err(crate::to_py_err)?;
+                Ok(result.into())
+            }
+        }
+
+        #[pymodule]
+        mod day7 {
+            use common::numeric::NumericMode;
+            use num_bigint::BigInt;
+            use pyo3::prelude::*;
+
+            #[pyfunction]
+            fn find_start(lines: Vec<String>) -> (usize, usize) {
+                ::day7::find_start(&crate::to_matrix(lines))
+            }
+
+            #[pyfunction]
+            fn propagate_quantum_tachyon(lines: Vec<String>) -> PyResult<BigInt> {
+                let matrix = crate::to_matrix(lines);
+                let (line, col) = ::day7::find_start(&matrix);
+                let result =
+                    ::day7::propagate_quantum_tachyon(&matrix, line, col, NumericMode::Big)
+                        .map_err(crate::to_py_err)?;
+                Ok(result.into())
+            }
+        }
+
+        #[pymodule]
+        mod day8 {
+            use ::day8::Pos;
+            use common::numeric::NumericMode;
+            use num_bigint::BigInt;
+            use pyo3::prelude::*;
+
+            #[pyfunction]
+            fn parse_junction(line: &str) -> PyResult<(i64, i64, i64)> {
+                let values: Vec<&str> = line.splitn(3, ',').collect();
+                if values.len() != 3 {
+                    return Err(pyo3::exceptions::PyValueError::new_err(format!(
+                        "expected x,y,z in {line:?}"
+                    )));
+                }
+                let pos = Pos::from(values).map_err(crate::to_py_err)?;
+                Ok((pos.x, pos.y, pos.z))
+            }
+
+            #[pyfunction]
+            fn create_circuit(
+                junctions: Vec<(i64, i64, i64)>,
+                connection_limit: usize,
+            ) -> PyResult<(BigInt, BigInt)> {
+                let junctions = junctions
+                    .into_iter()
+                    .map(|(x, y, z)| Pos { x, y, z })
+                    .collect();
+                let (res, wall) =
+                    ::day8::create_circuit(&junctions, connection_limit, NumericMode::Big)
+                        .map_err(crate::to_py_err)?;
+                Ok((res.into(), wall.into()))
+            }
+        }
+
+        #[pymodule]
+        mod day9 {
+            use ::day9::Position;
+            use common::numeric::NumericMode;
+            use num_bigint::BigInt;
+            use pyo3::{exceptions::PyValueError, prelude::*};
+
+            type Pair = Option<((i64, i64), (i64, i64))>;
+
+            // part 2 rasterizes the bounding box of the polygon, min..=max on both axes, like in
+            // the playground a failed allocation would abort the interpreter
+            const MAX_POLYGON_CELLS: i64 = 10_000_000;
+
+            fn to_positions(positions: Vec<(i64, i64)>) -> Vec<Position> {
+                positions
+                    .into_iter()
+                    .map(|(x, y)| Position { x, y })
+                    .collect()
+            }
+
+            #[pyfunction]
+            fn parse_line(line: String) -> PyResult<(i64, i64)> {
+                let pos = ::day9::parse_line(&line).map_err(crate::to_py_err)?;
+                Ok((pos.x, pos.y))
+            }
+
+            #[pyfunction]
+            fn best_rectangle(positions: Vec<(i64, i64)>) -> PyResult<(Pair, BigInt)> {
+                let (pair, size) =
+                    ::day9::best_rectangle(&to_positions(positions), NumericMode::Big)
+                        .map_err(|err| PyValueError::new_err(err.to_string()))?;
+                Ok((pair.map(|(l, r)| ((l.x, l.y), (r.x, r.y))), size.into()))
+            }
+
+            #[pyfunction]
+            fn best_rectangle_part2(positions: Vec<(i64, i64)>) -> PyResult<(Pair, BigInt)> {
+                let vertices = to_positions(positions);
+                let (Some(min_x), Some(max_x)) = (
+                    vertices.iter().map(|p| p.x).min(),
+                    vertices.iter().map(|p| p.x).max(),
+                ) else {
+                    return Err(PyValueError::new_err("no red tile"));
+                };
+                let min_y = vertices.iter().map(|p| p.y).min().unwrap();
+                let max_y = vertices.iter().map(|p| p.y).max().unwrap();
+                let width = max_x.saturating_sub(min_x).saturating_add(1);
+                let height = max_y.saturating_sub(min_y).saturating_add(1);
+                if width.saturating_mul(height) > MAX_POLYGON_CELLS {
+                    return Err(PyValueError::new_err(format!(
+                        "a {width}x{height} polygon is too big to rasterize"
+                    )));
+                }
+                let (pair, size) = ::day9::best_rectangle_part2(&vertices, NumericMode::Big)
+                    .map_err(|err| PyValueError::new_err(err.to_string()))?;
+                Ok((pair.map(|(l, r)| ((l.x, l.y), (r.x, r.y))), size.into()))
+            }
+        }
+    }
+}
@@ -0,0 +1,204 @@
+import unittest
+
+import aoc
+
+
+class Day1(unittest.TestCase):
+    def test_parse_line(self):
+        self.assertEqual(aoc.y2025.day1.parse_instruction("L68"), -68)
+        self.assertEqual(aoc.y2025.day1.parse_instruction("+48"), 48)
+        self.assertEqual(aoc.y2025.day1.parse_line("R48, l5"), [48, -5])
+        self.assertEqual(aoc.y2025.day1.parse_line(""), [])
+        with self.assertRaisesRegex(ValueError, "line 3"):
+            aoc.y2025.day1.parse_line("X12", 3)
+
+    def test_roll_value(self):
+        self.assertEqual(aoc.y2025.day1.roll_value(50, -150), (0, 2))
+        self.assertEqual(aoc.y2025.day1.roll_value(50, 100), (50, 1))
+        self.assertEqual(aoc.y2025.day1.roll_value(50, 2**63 - 1), (57, 92233720368547758))
+        with self.assertRaises(ValueError):
+            aoc.y2025.day1.roll_value(50, -(2**63))
+
+    def test_count_zeros(self):
+        rotations = [-68, -30, 48, -5, 60, -55, -1, -99, 14, -82]
+        self.assertEqual(aoc.y2025.day1.count_zeros(rotations), (3, 6))
+        self.assertEqual(aoc.y2025.day1.count_zeros([50, 100], start=50), (2, 2))
+        self.assertEqual(aoc.y2025.day1.count_zeros([5, -25, 3], start=5, size=10, targets=[0, 5]), (2, 6))
+        with self.assertRaises(ValueError):
+            aoc.y2025.day1.count_zeros([1], size=10)
+        with self.assertRaises(ValueError):
+            aoc.y2025.day1.count_zeros([-(2**63)])
+
+
+class Day2(unittest.TestCase):
+    def test_detect_bad_id(self):
+        self.assertTrue(aoc.y2025.day2.detect_bad_id("824824824"))
+        self.assertFalse(aoc.y2025.day2.detect_bad_id("1234567890"))
+        self.assertFalse(aoc.y2025.day2.detect_bad_id("824824824", "2"))
+        self.assertTrue(aoc.y2025.day2.detect_bad_id("824824824", "3,5"))
+        with self.assertRaises(ValueError):
+            aoc.y2025.day2.detect_bad_id("11", "1")
+
+    def test_sum_ranges(self):
+        ranges = aoc.y2025.day2.parse_ranges("11-22,95-115,\n998-1012,")
+        self.assertEqual(ranges, [(11, 22), (95, 115), (998, 1012)])
+        self.assertEqual(aoc.y2025.day2.parse_ranges("aa-ff", 16), [(0xAA, 0xFF)])
+        self.assertEqual(sum(aoc.y2025.day2.sum_bad_ids(b, e) for b, e in ranges), 33 + 210 + 2009)
+        self.assertEqual(aoc.y2025.day2.sum_repeated_ids(95, 115, "2"), 99)
+        self.assertEqual(aoc.y2025.day2.sum_repeated_ids(1, 2**100), aoc.y2025.day2.sum_repeated_ids(1, 2**100, "2+"))
+        self.assertEqual(aoc.y2025.day2.sum_repeated_ids(0xA0, 0xFF, "2", 16), sum(0x11 * d for d in range(10, 16)))
+        with self.assertRaisesRegex(ValueError, "reversed range"):
+            aoc.y2025.day2.parse_ranges("33-20")
+        with self.assertRaises(ValueError):
+            aoc.y2025.day2.parse_ranges("1-2", 37)
+        with self.assertRaises(ValueError):
+            aoc.y2025.day2.sum_repeated_ids(1, 2, "2", 1)
+
+
+class Day3(unittest.TestCase):
+    def test_compute_joltage(self):
+        self.assertEqual(aoc.y2025.day3.compute_joltage("818181911112111", 2), 92)
+        self.assertEqual(aoc.y2025.day3.compute_joltage("818181911112111", 12), 888911112111)
+        self.assertEqual(aoc.y2025.day3.compute_joltage("9" * 30 + "1", 25), int("9" * 25))
+        with self.assertRaises(ValueError):
+            aoc.y2025.day3.compute_joltage("12", 3)
+        with self.assertRaises(ValueError):
+            aoc.y2025.day3.compute_joltage("12", 0)
+        with self.assertRaises(ValueError):
+            aoc.y2025.day3.compute_joltage("1x2", 1)
+
+
+GRID = [
+    "..@@.@@@@.",
+    "@@@.@.@.@@",
+    "@@@@@.@.@@",
+    "@.@@@@..@.",
+    "@@.@@@@.@@",
+    ".@@@@@@@.@",
+    ".@.@.@.@@@",
+    "@.@@@.@@@@",
+    ".@@@@@@@@.",
+    "@.@.@@@.@.",
+]
+
+
+class Day4(unittest.TestCase):
+    def test_process_floor(self):
+        count, processed = aoc.y2025.day4.process_floor(GRID)
+        self.assertEqual(count, 13)
+        self.assertEqual(processed[0], "..xx.xx@x.")
+
+    def test_remove_all_rolls(self):
+        self.assertEqual(aoc.y2025.day4.remove_all_rolls(GRID), 43)
+
+
+class Day5(unittest.TestCase):
+    def test_parse_range(self):
+        self.assertEqual(aoc.y2025.day5.parse_range("2-1"), (1, 2))
+        with self.assertRaises(ValueError):
+            aoc.y2025.day5.parse_range("12-")
+
+    def test_consolidate_ranges(self):
+        ranges = [(3, 5), (10, 14), (16, 20), (12, 18)]
+        self.assertEqual(aoc.y2025.day5.consolidate_ranges(ranges), [(3, 5), (10, 20)])
+        self.assertTrue(aoc.y2025.day5.in_range((3, 5), 5))
+
+    def test_solve(self):
+        example = "3-5\n10-14\n16-20\n12-18\n\n1\n5\n8\n11\n17\n32\n"
+        self.assertEqual(aoc.y2025.day5.solve(example), (3, 14))
+        self.assertEqual(aoc.y2025.day5.solve(example.replace("\n", "\r\n")), (3, 14))
+        with self.assertRaises(ValueError):
+            aoc.y2025.day5.solve("3-5\n\nx\n")
+
+
+class Day6(unittest.TestCase):
+    def test_grand_total(self):
+        lines = [
+            "123 328  51 64 ",
+            " 45 64  387 23 ",
+            "  6 98  215 314",
+            "*   +   *   +  ",
+        ]
+        self.assertEqual(aoc.y2025.day6.grand_total(lines), 3263827)
+
+
+class Day7(unittest.TestCase):
+    def test_propagate_quantum_tachyon(self):
+        lines = [
+            ".......S.......",
+            "...............",
+            ".......^.......",
+            "...............",
+            "......^.^......",
+            "...............",
+        ]
+        self.assertEqual(aoc.y2025.day7.find_start(lines), (1, 7))
+        self.assertEqual(aoc.y2025.day7.propagate_quantum_tachyon(lines), 4)
+
+
+JUNCTIONS = """162,817,812
+57,618,57
+906,360,560
+592,479,940
+352,342,300
+466,668,158
+542,29,236
+431,825,988
+739,650,466
+52,470,668
+216,146,977
+819,987,18
+117,168,530
+805,96,715
+346,949,466
+970,615,88
+941,993,340
+862,61,35
+984,92,344
+425,690,689"""
+
+
+class Day8(unittest.TestCase):
+    def test_create_circuit(self):
+        junctions = [aoc.y2025.day8.parse_junction(line) for line in JUNCTIONS.splitlines()]
+        self.assertEqual(junctions[0], (162, 817, 812))
+        self.assertEqual(aoc.y2025.day8.create_circuit(junctions, 10)[0], 40)
+        self.assertEqual(aoc.y2025.day8.create_circuit(junctions, 0)[1], 25272)
+
+    def test_big_numbers(self):
+        far = [(3_000_000_000, 0, 0), (4_000_000_000, 0, 0)]
+        _, wall = aoc.y2025.day8.create_circuit(far, 0)
+        self.assertEqual(wall, 12_000_000_000_000_000_000)
+
+
+VERTICES = [(7, 1), (11, 1), (11, 7), (9, 7), (9, 5), (2, 5), (2, 3), (7, 3)]
+
+
+class Day9(unittest.TestCase):
+    def test_parse_line(self):
+        self.assertEqual(aoc.y2025.day9.parse_line("7,1"), (7, 1))
+        with self.assertRaises(ValueError):
+            aoc.y2025.day9.parse_line("7")
+
+    def test_best_rectangle(self):
+        self.assertEqual(aoc.y2025.day9.best_rectangle(VERTICES)[1], 50)
+        pair, size = aoc.y2025.day9.best_rectangle_part2(VERTICES)
+        self.assertEqual(size, 24)
+        self.assertIsNotNone(pair)
+        with self.assertRaises(ValueError):
+            aoc.y2025.day9.best_rectangle_part2([(0, 0), (100_000, 0), (100_000, 100_000), (0, 100_000)])
+        with self.assertRaises(ValueError):
+            aoc.y2025.day9.best_rectangle_part2([])
+        # the grid spans the tiles only, not the origin
+        far = [(x - 10**9, y - 10**9) for x, y in VERTICES]
+        self.assertEqual(aoc.y2025.day9.best_rectangle_part2(far)[1], 24)
+        with self.assertRaises(ValueError):
+            aoc.y2025.day9.best_rectangle_part2([(-5000, -5000), (-1, -5000), (-1, -1), (-5000, -1)])
+
+    def test_big_rectangle(self):
+        _, size = aoc.y2025.day9.best_rectangle([(0, 0), (5_000_000_000, 5_000_000_000)])
+        self.assertEqual(size, 25000000010000000001)
+
+
+if __name__ == "__main__":
+    unittest.main()
@@ -0,0 +1,465 @@
+use common::args::Args;
+use common::numeric::NumericMode;
+use day1::trace::{Trace, TraceRow};
+use day5::RangeId;
+use day8::Pos;
+use day9::Position;
+use num_bigint::BigUint;
+use std::{
+    fs,
+    io::{self, BufRead, Write},
+    path::PathBuf,
+};
+
+const HELP: &str = "commands:
+  load [year/]<day> [path]   parse a day input like 8, day8 or 2025/day8,
+                             default to ../<year>/day<day>/src/input.txt
+  show                       print the loaded input
+  roll <start> <rotation>..  day1: turn the dial, rotations as L68, R48 or -68, 48
+  badid [id..]               day2: check ids for repeated patterns, or sum the loaded ranges
+  joltage [bank] <size>      day3: best joltage picking <size> batteries, or of the loaded banks
+  floor                      day4: one pass of roll removal on the loaded grid
+  merge [range..]            day5: consolidate ranges like 3-5 5-14, or the loaded ones
+  total                      day6: grand total of the loaded worksheet
+  timelines                  day7: beams and timeline count of the loaded manifold
+  circuits <limit>           day8: connect the <limit> closest junctions, 0 for all
+  rectangle                  day9: largest rectangle (part 1) of the loaded red tiles
+  help                       this message
+  quit                       leave";
+
+#[derive(Debug)]
+enum Loaded {
+    Day1(Vec<i64>),
+    Day2(Vec<(u128, u128)>),
+    Day3(Vec<String>),
+    Grid(u32, Vec<Vec<u8>>),
+    Day5(Vec<RangeId>, Vec<u64>),
+    Day8(Vec<Pos>),
+    Day9(Vec<Position>),
+}
+
+struct Session {
+    mode: NumericMode,
+    loaded: Option<Loaded>,
+}
+
+fn parse_rotation(token: &str) -> Result<i64, String> {
+    // a bare number turns right
+    day1::parse_instruction(token).or_else(|err| token.parse().map_err(|_| err.to_string()))
+}
+
+fn parse_range(token: &str) -> Result<RangeId, String> {
+    token.parse::<RangeId>().map_err(|err| err.badline)
+}
+
+// only this year is solved for now
+const LATEST_YEAR: u32 = 2025;
+
+// accept 8, day8, 2025/8 and 2025/day8
+fn parse_day(token: Option<&str>) -> Result<(u32, u32), String> {
+    let token = token.ok_or("missing day")?;
+    let (year, day) = match token.split_once('/') {
+        Some((year, day)) => (parse_number(Some(year), "year")?, day),
+        None => (LATEST_YEAR, token),
+    };
+    let day = parse_number(Some(day.strip_prefix("day").unwrap_or(day)), "day")?;
+    Ok((year, day))
+}
+
+fn parse_number<T: std::str::FromStr>(token: Option<&str>, what: &str) -> Result<T, String> {
+    let token = token.ok_or(format!("missing {what}"))?;
+    token
+        .parse()
+        .map_err(|_| format!("invalid {what} {token:?}"))
+}
+
+fn parse_input(day: u32, input: &str) -> Result<Loaded, String> {
+    let lines = input.lines().map(str::trim_end).filter(|l| !l.is_empty());
+    match day {
+        1 => Ok(Loaded::Day1(
+            day1::parse_input(input).map_err(|err| err.to_string())?,
+        )),
+        2 => Ok(Loaded::Day2(
+            day2::parse_ranges(input, 10).map_err(|err| err.to_string())?,
+        )),
+        3 => Ok(Loaded::Day3(lines.map(|l| l.trim().to_string()).collect())),
+        4 | 6 | 7 => Ok(Loaded::Grid(
+            day,
+            input
+                .lines()
+                .filter(|l| !l.trim().is_empty())
+                .map(|l| l.as_bytes().to_vec())
+                .collect(),
+        )),
+        5 => {
+            let (ranges, ids) = input
+                .split_once("\n\n")
+                .ok_or("expected ranges, an empty line, then ids")?;
+            Ok(Loaded::Day5(
+                ranges
+                    .lines()
+                    .map(|l| parse_range(l.trim()))
+                    .collect::<Result<_, _>>()?,
+                ids.lines()
+                    .filter(|l| !l.trim().is_empty())
+                    .map(|l| parse_number(Some(l.trim()), "id"))
+                    .collect::<Result<_, _>>()?,
+            ))
+        }
+        8 => {
+            let mut juctions = Vec::new();
+            for line in lines {
+                let values: Vec<&str> = line.splitn(3, ',').collect();
+                if values.len() != 3 {
+                    return Err(format!("expected x,y,z in {line:?}"));
+                }
+                juctions.push(Pos::from(values).map_err(|err| err.to_string())?);
+            }
+            Ok(Loaded::Day8(juctions))
+        }
+        9 => Ok(Loaded::Day9(
+            lines
+                .map(|l| day9::parse_line(&l.to_string()).map_err(|err| err.to_string()))
+                .collect::<Result<_, _>>()?,
+        )),
+        _ => Err(format!("day {day} is not solved yet")),
+    }
+}
+
+fn format_grid(grid: &[Vec<u8>]) -> String {
+    grid.iter()
+        .map(|l| String::from_utf8_lossy(l).into_owned())
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+impl Session {
+    fn load(&mut self, year: u32, day: u32, path: Option<&str>) -> Result<String, String> {
+        if year != LATEST_YEAR {
+            return Err(format!("year {year} is not solved yet"));
+        }
+        let path = match path {
+            Some(p) => PathBuf::from(p),
+            None => PathBuf::from(format!("../{year}/day{day}/src/input.txt")),
+        };
+        let input =
+            fs::read_to_string(&path).map_err(|err| format!("cannot read {path:?}: {err}"))?;
+        let loaded = parse_input(day, &input)?;
+        let summary = match &loaded {
+            Loaded::Day1(v) => format!("{} rotations", v.len()),
+            Loaded::Day2(v) => format!("{} ranges", v.len()),
+            Loaded::Day3(v) => format!("{} banks", v.len()),
+            Loaded::Grid(_, v) => format!("{} lines grid", v.len()),
+            Loaded::Day5(r, i) => format!("{} ranges, {} ids", r.len(), i.len()),
+            Loaded::Day8(v) => format!("{} junctions", v.len()),
+            Loaded::Day9(v) => format!("{} red tiles", v.len()),
+        };
+        self.loaded = Some(loaded);
+        Ok(format!(
+            "{year}/day{day} loaded from {}: {summary}",
+            path.display()
+        ))
+    }
+
+    fn loaded_grid(&self, expected_day: u32) -> Result<&Vec<Vec<u8>>, String> {
+        match &self.loaded {
+            Some(Loaded::Grid(day, grid)) if *day == expected_day => Ok(grid),
+            _ => Err(format!("load day{expected_day} first")),
+        }
+    }
+
+    fn execute(&mut self, line: &str) -> Result<String, String> {
+        let mut tokens = line.split_whitespace();
+        let Some(command) = tokens.next() else {
+            return Ok(String::new());
+        };
+        let args: Vec<&str> = tokens.collect();
+
+        match command {
+            "help" => Ok(HELP.to_string()),
+            "load" => {
+                let (year, day) = parse_day(args.first().copied())?;
+                self.load(year, day, args.get(1).copied())
+            }
+            "show" => match &self.loaded {
+                Some(Loaded::Grid(_, grid)) => Ok(format_grid(grid)),
+                Some(loaded) => Ok(format!("{loaded:#?}")),
+                None => Err("nothing loaded".to_string()),
+            },
+            "roll" => {
+                let start: i32 = parse_number(args.first().copied(), "start")?;
+                let rotations: Vec<i64> = match (&self.loaded, args.len()) {
+                    (Some(Loaded::Day1(rotations)), 1) => rotations.clone(),
+                    _ => args[1..]
+                        .iter()
+                        .map(|t| parse_rotation(t))
+                        .collect::<Result<_, _>>()?,
+                };
+                let trace = Trace::default();
+                let mut output = vec![trace.header()];
+                let dial = day1::Dial::new(100, start, vec![0]).map_err(|err| err.to_string())?;
+                let mut counter = dial.counter(self.mode);
+                for (idx, rotation) in rotations.into_iter().enumerate() {
+                    let before = counter.cursor;
+                    let clicks = counter.roll(rotation).map_err(|err| err.to_string())?;
+                    output.extend(trace.row(&TraceRow {
+                        line: idx + 1,
+                        instruction: day1::format_instruction(rotation),
+                        before,
+                        after: counter.cursor,
+                        clicks,
+                    }));
+                }
+                output.push(format!(
+                    "final position {}, landed on zero {}, total clicks {}",
+                    counter.cursor, counter.landed, counter.clicks
+                ));
+                Ok(output.join("\n"))
+            }
+            "badid" => match (&self.loaded, args.is_empty()) {
+                (Some(Loaded::Day2(ranges)), true) => {
+                    let rules = [day2::RepeatRule::PART2];
+                    let sum = |ranges: &[(u128, u128)], mode| {
+                        day2::sum_ranges(ranges, &rules, mode, 10).remove(0)
+                    };
+                    let mut output: Vec<String> = ranges
+                        .iter()
+                        .map(|&(begin, end)| {
+                            let range_sum = sum(&[(begin, end)], day2::CountMode::PerRange);
+                            format!("{begin}-{end}: {range_sum}")
+                        })
+                        .collect();
+                    // an id in several ranges is counted once, as day2 does
+                    let total = sum(ranges, day2::CountMode::Once);
+                    output.push(format!("sum of bad ids: {total}"));
+                    Ok(output.join("\n"))
+                }
+                (_, true) => Err("missing id".to_string()),
+                _ => Ok(args
+                    .iter()
+                    .map(|id| format!("{id}: {}", day2::detect_bad_id(id)))
+                    .collect::<Vec<_>>()
+                    .join("\n")),
+            },
+            "joltage" => {
+                let (banks, size) = match (&self.loaded, args.len()) {
+                    (Some(Loaded::Day3(banks)), 1) => {
+                        (banks.clone(), parse_number(args.first().copied(), "size")?)
+                    }
+                    _ => (
+                        vec![args.first().ok_or("missing bank")?.to_string()],
+                        parse_number(args.get(1).copied(), "size")?,
+                    ),
+                };
+                let mut output = Vec::new();
+                let mut total = BigUint::default();
+                for bank in &banks {
+                    let joltage =
+                        day3::compute_joltage(bank, size).map_err(|err| err.to_string())?;
+                    output.push(format!("{bank}: {joltage}"));
+                    total += joltage;
+                }
+                if banks.len() == 1 {
+                    return Ok(total.to_string());
+                }
+                output.push(format!("sum of joltage: {total}"));
+                Ok(output.join("\n"))
+            }
+            "floor" => {
+                let grid = self.loaded_grid(4)?;
+                let (count, processed) = day4::process_floor(grid);
+                let output = format!("{}\n{count} rolls removed", format_grid(&processed));
+                self.loaded = Some(Loaded::Grid(4, processed));
+                Ok(output)
+            }
+            "merge" => {
+                let ranges: Vec<RangeId> = match (&self.loaded, args.is_empty()) {
+                    (Some(Loaded::Day5(ranges, _)), true) => ranges.clone(),
+                    (_, true) => return Err("missing ranges".to_string()),
+                    _ => args
+                        .iter()
+                        .map(|t| parse_range(t))
+                        .collect::<Result<_, _>>()?,
+                };
+                let merged = day5::consolidate_ranges(ranges);
+                let mut output: Vec<String> = merged
+                    .iter()
+                    .map(|r| format!("{}-{}", r.min, r.max))
+                    .collect();
+                let total: u64 = merged.iter().map(|r| r.max - r.min + 1).sum();
+                output.push(format!("{} ranges covering {total} ids", merged.len()));
+                Ok(output.join("\n"))
+            }
+            "total" => {
+                let grid = self.loaded_grid(6)?;
+                let total = day6::grand_total(grid, self.mode).map_err(|err| err.to_string())?;
+                Ok(format!("grand total of operation: {total}"))
+            }
+            "timelines" => {
+                let grid = self.loaded_grid(7)?;
+                let (line, col) = day7::find_start(grid);
+                let count = day7::propagate_quantum_tachyon(grid, line, col, self.mode)
+                    .map_err(|err| err.to_string())?;
+                Ok(format!(
+                    "{}\n{count} timelines",
+                    format_grid(&day7::draw_beams(grid, line, col))
+                ))
+            }
+            "circuits" => {
+                let Some(Loaded::Day8(juctions)) = &self.loaded else {
+                    return Err("load day8 first".to_string());
+                };
+                let limit: usize = parse_number(args.first().copied(), "limit")?;
+                let (res, wall) = day8::create_circuit(juctions, limit, self.mode)
+                    .map_err(|err| err.to_string())?;
+                Ok(format!(
+                    "juction network size: {res}, distance to wall: {wall}"
+                ))
+            }
+            "rectangle" => {
+                let Some(Loaded::Day9(positions)) = &self.loaded else {
+                    return Err("load day9 first".to_string());
+                };
+                let (pair, size) =
+                    day9::best_rectangle(positions, self.mode).map_err(|err| err.to_string())?;
+                Ok(format!("best rectangle: {size}, pair: {pair:?}"))
+            }
+            _ => Err(format!("unknown command {command:?}, try help")),
+        }
+    }
+}
+
+fn main() -> io::Result<()> {
+    let mut session = Session {
+        mode: NumericMode::from_args(&Args::from_env(NumericMode::FLAGS)?)?,
+        loaded: None,
+    };
+    let stdin = io::stdin();
+    let mut stdout = io::stdout();
+
+    println!("advent of code repl, type help for the commands");
+    loop {
+        print!("> ");
+        stdout.flush()?;
+        let mut line = String::new();
+        if stdin.lock().read_line(&mut line)? == 0 {
+            break;
+        }
+        let line = line.trim();
+        if line == "quit" || line == "exit" {
+            break;
+        }
+        match session.execute(line) {
+            Ok(output) if output.is_empty() => {}
+            Ok(output) => println!("{output}"),
+            Err(err) => println!("error: {err}"),
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn session() -> Session {
+        Session {
+            mode: NumericMode::Checked,
+            loaded: None,
+        }
+    }
+
+    #[test]
+    fn test_roll() {
+        let output = session().execute("roll 50 -150").unwrap();
+        assert!(output.ends_with("final position 0, landed on zero 1, total clicks 2"));
+        let output = session().execute("roll 50 L68 L30 R48").unwrap();
+        assert!(output.ends_with("final position 0, landed on zero 1, total clicks 2"));
+        assert!(session().execute("roll 50 X3").is_err());
+    }
+
+    #[test]
+    fn test_merge() {
+        let output = session().execute("merge 3-5 5-14 16-20").unwrap();
+        assert_eq!(output, "3-14\n16-20\n2 ranges covering 17 ids");
+        assert!(session().execute("merge").is_err());
+    }
+
+    #[test]
+    fn test_joltage() {
+        let output = session().execute("joltage 818181911112111 12").unwrap();
+        assert_eq!(output, "888911112111");
+        assert!(session().execute("joltage 12 3").is_err());
+        assert!(session().execute("joltage 123 0").is_err());
+        assert!(session().execute("joltage 12a 1").is_err());
+    }
+
+    #[test]
+    fn test_loaded_banks() {
+        let mut session = session();
+        session.loaded = Some(parse_input(3, "987654321111111\n811111111111119\n").unwrap());
+        let output = session.execute("joltage 2").unwrap();
+        assert_eq!(
+            output,
+            "987654321111111: 98\n811111111111119: 89\nsum of joltage: 187"
+        );
+    }
+
+    #[test]
+    fn test_loaded_ranges() {
+        let mut session = session();
+        let big = "1".repeat(24);
+        let input = format!("11-22,95-115,20-22,{big}-{big}");
+        session.loaded = Some(parse_input(2, &input).unwrap());
+        let output = session.execute("badid").unwrap();
+        // 22 is in two ranges and counted once in the total
+        assert_eq!(
+            output,
+            format!(
+                "11-22: 33\n95-115: 210\n20-22: 22\n{big}-{big}: {big}\nsum of bad ids: {}",
+                243 + big.parse::<u128>().unwrap()
+            )
+        );
+    }
+
+    #[test]
+    fn test_circuits() {
+        let mut session = session();
+        assert!(session.execute("circuits 10").is_err());
+        session.loaded = Some(
+            parse_input(
+                8,
+                "162,817,812\n57,618,57\n906,360,560\n592,479,940\n352,342,300\n466,668,158\n\
+                 542,29,236\n431,825,988\n739,650,466\n52,470,668\n216,146,977\n819,987,18\n\
+                 117,168,530\n805,96,715\n346,949,466\n970,615,88\n941,993,340\n862,61,35\n\
+                 984,92,344\n425,690,689\n",
+            )
+            .unwrap(),
+        );
+        let output = session.execute("circuits 10").unwrap();
+        assert_eq!(output, "juction network size: 40, distance to wall: 0");
+    }
+
+    #[test]
+    fn test_parse_day() {
+        assert_eq!(parse_day(Some("8")).unwrap(), (2025, 8));
+        assert_eq!(parse_day(Some("day8")).unwrap(), (2025, 8));
+        assert_eq!(parse_day(Some("2025/day8")).unwrap(), (2025, 8));
+        assert_eq!(parse_day(Some("2026/3")).unwrap(), (2026, 3));
+        assert!(parse_day(Some("2025/")).is_err());
+        assert!(parse_day(None).is_err());
+        assert!(session().execute("load 2026/day1").is_err());
+    }
+
+    #[test]
+    fn test_parse_input() {
+        assert!(matches!(
+            parse_input(5, "3-5\n10-14\n\n1\n5\n").unwrap(),
+            Loaded::Day5(ranges, ids) if ranges.len() == 2 && ids == vec![1, 5]
+        ));
+        assert!(parse_input(1, "L68\nX12\n").is_err());
+        assert!(parse_input(12, "").is_err());
+        assert!(session().execute("frobnicate").is_err());
+    }
+}
@@ -0,0 +1,6 @@
+#!/bin/sh
+# build the solver for the browser and copy it next to the playground page
+set -e
+cd "$(dirname "$0")"
+cargo build --release --target wasm32-unknown-unknown
+cp target/wasm32-unknown-unknown/release/aoc_wasm.wasm www/
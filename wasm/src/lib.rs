@@ -0,0 +1,48 @@
+pub mod solve;
+
+use std::cell::RefCell;
+
+// minimal C-like ABI, no bindgen needed, see www/aoc.js for the javascript side:
+// the host copies the input in a buffer from `alloc`, calls `solve` which returns the length
+// of a json answer located at `output_ptr`, then frees its buffer with `dealloc`
+thread_local! {
+    static OUTPUT: RefCell<Vec<u8>> = const { RefCell::new(Vec::new()) };
+}
+
+#[unsafe(no_mangle)]
+pub extern "C" fn alloc(len: usize) -> *mut u8 {
+    let mut buffer = Vec::with_capacity(len);
+    let ptr = buffer.as_mut_ptr();
+    std::mem::forget(buffer);
+    ptr
+}
+
+/// # Safety
+/// `ptr` and `len` must come from a previous call to `alloc`
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn dealloc(ptr: *mut u8, len: usize) {
+    drop(unsafe { Vec::from_raw_parts(ptr, 0, len) });
+}
+
+/// # Safety
+/// `ptr` must point to `len` initialized bytes
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn solve(year: u32, day: u32, ptr: *const u8, len: usize) -> usize {
+    let bytes = unsafe { std::slice::from_raw_parts(ptr, len) };
+    let json = match str::from_utf8(bytes) {
+        Ok(input) => match solve::solve(year, day, input) {
+            Ok(answer) => answer.to_json(),
+            Err(err) => solve::error_json(&err),
+        },
+        Err(err) => solve::error_json(&format!("input is not utf8: {err}")),
+    };
+    OUTPUT.with_borrow_mut(|output| {
+        *output = json.into_bytes();
+        output.len()
+    })
+}
+
+#[unsafe(no_mangle)]
+pub extern "C" fn output_ptr() -> *const u8 {
+    OUTPUT.with_borrow(|output| output.as_ptr())
+}
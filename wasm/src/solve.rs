@@ -0,0 +1,384 @@
+use common::numeric::NumericMode;
+use day5::RangeId;
+use day8::Pos;
+use num_bigint::BigUint;
+
+// the playground has no `--numeric` flag, it never overflows instead
+const MODE: NumericMode = NumericMode::Big;
+
+// drawing bigger grids would freeze the page
+const MAX_GRID_WIDTH: i64 = 120;
+const MAX_POLYGON_CELLS: i64 = 10_000_000;
+
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct Answer {
+    pub part1: Option<String>,
+    pub part2: Option<String>,
+    pub grid: Option<Vec<String>>,
+    pub note: Option<String>,
+}
+
+fn json_string(value: &str) -> String {
+    let mut result = String::with_capacity(value.len() + 2);
+    result.push('"');
+    for c in value.chars() {
+        match c {
+            '"' => result.push_str("\\\""),
+            '\\' => result.push_str("\\\\"),
+            '\n' => result.push_str("\\n"),
+            '\r' => result.push_str("\\r"),
+            '\t' => result.push_str("\\t"),
+            c if (c as u32) < 0x20 => result.push_str(&format!("\\u{:04x}", c as u32)),
+            c => result.push(c),
+        }
+    }
+    result.push('"');
+    result
+}
+
+fn json_option(value: &Option<String>) -> String {
+    match value {
+        Some(v) => json_string(v),
+        None => "null".to_string(),
+    }
+}
+
+impl Answer {
+    pub fn to_json(&self) -> String {
+        let grid = match &self.grid {
+            Some(lines) => format!(
+                "[{}]",
+                lines
+                    .iter()
+                    .map(|l| json_string(l))
+                    .collect::<Vec<_>>()
+                    .join(",")
+            ),
+            None => "null".to_string(),
+        };
+        format!(
+            "{{\"part1\":{},\"part2\":{},\"grid\":{},\"note\":{},\"error\":null}}",
+            json_option(&self.part1),
+            json_option(&self.part2),
+            grid,
+            json_option(&self.note)
+        )
+    }
+}
+
+pub fn error_json(err: &str) -> String {
+    format!(
+        "{{\"part1\":null,\"part2\":null,\"grid\":null,\"note\":null,\"error\":{}}}",
+        json_string(err)
+    )
+}
+
+fn lines(input: &str) -> impl Iterator<Item = &str> {
+    input.lines().map(str::trim_end).filter(|l| !l.is_empty())
+}
+
+fn matrix(input: &str) -> Vec<Vec<u8>> {
+    lines(input).map(|l| l.as_bytes().to_vec()).collect()
+}
+
+fn grid(mat: Vec<Vec<u8>>) -> Option<Vec<String>> {
+    Some(
+        mat.into_iter()
+            .map(|l| String::from_utf8_lossy(&l).into_owned())
+            .collect(),
+    )
+}
+
+fn solve_day1(input: &str) -> Result<Answer, String> {
+    let mut counter = day1::Dial::default().counter(MODE);
+    for rotation in day1::parse_input(input).map_err(|err| err.to_string())? {
+        counter.roll(rotation).map_err(|err| err.to_string())?;
+    }
+    Ok(Answer {
+        part1: Some(counter.landed.to_string()),
+        part2: Some(counter.clicks.to_string()),
+        ..Default::default()
+    })
+}
+
+fn solve_day2(input: &str) -> Result<Answer, String> {
+    let ranges = day2::parse_ranges(input, 10).map_err(|err| err.to_string())?;
+    let rules = [day2::RepeatRule::PART1, day2::RepeatRule::PART2];
+    let sums = day2::sum_ranges(&ranges, &rules, day2::CountMode::Once, 10);
+    let overlaps: Vec<String> = day2::overlapping_ranges(&ranges)
+        .into_iter()
+        .map(|((lb, le), (rb, re))| format!("{lb}-{le} and {rb}-{re}"))
+        .collect();
+    Ok(Answer {
+        part1: Some(sums[0].to_string()),
+        part2: Some(sums[1].to_string()),
+        note: (!overlaps.is_empty())
+            .then(|| format!("overlapping ranges counted once: {}", overlaps.join(", "))),
+        ..Default::default()
+    })
+}
+
+fn solve_day3(input: &str) -> Result<Answer, String> {
+    let mut part1 = BigUint::default();
+    let mut part2 = BigUint::default();
+    for (idx, line) in lines(input).enumerate() {
+        let line = line.trim();
+        if line.len() < 12 || !line.bytes().all(|b| b.is_ascii_digit()) {
+            return Err(format!("line {}: expected at least 12 digits", idx + 1));
+        }
+        let joltage = |size| {
+            day3::compute_joltage(line, size).map_err(|err| format!("line {}: {err}", idx + 1))
+        };
+        part1 += joltage(2)?;
+        part2 += joltage(12)?;
+    }
+    Ok(Answer {
+        part1: Some(part1.to_string()),
+        part2: Some(part2.to_string()),
+        ..Default::default()
+    })
+}
+
+fn solve_day4(input: &str) -> Result<Answer, String> {
+    let roll_map = matrix(input);
+    let (part1, first_pass) = day4::process_floor(&roll_map);
+    let part2 = day4::remove_all_rolls(roll_map);
+    Ok(Answer {
+        part1: Some(part1.to_string()),
+        part2: Some(part2.to_string()),
+        grid: grid(first_pass),
+        ..Default::default()
+    })
+}
+
+fn solve_day5(input: &str) -> Result<Answer, String> {
+    // split on the first blank line once the line endings are gone, pasted text may use \r\n
+    let mut input_lines = input.lines().map(str::trim);
+    let mut valid_range_ids = Vec::new();
+    let mut separated = false;
+    for line in input_lines.by_ref() {
+        if line.is_empty() {
+            separated = true;
+            break;
+        }
+        valid_range_ids.push(line.parse::<RangeId>().map_err(|err| err.badline)?);
+    }
+    if !separated {
+        return Err("expected ranges, an empty line, then ids".to_string());
+    }
+    let valid_range_ids = day5::consolidate_ranges(valid_range_ids);
+
+    let mut fresh_ingredient = 0;
+    for line in input_lines.filter(|line| !line.is_empty()) {
+        let id: u64 = line
+            .trim()
+            .parse()
+            .map_err(|err| format!("failed to parse {line:?}: {err}"))?;
+        if valid_range_ids
+            .iter()
+            .any(|range| day5::in_range(range, id))
+        {
+            fresh_ingredient += 1;
+        }
+    }
+    let fresh_ids: u64 = valid_range_ids.iter().map(|r| r.max - r.min + 1).sum();
+    Ok(Answer {
+        part1: Some(fresh_ingredient.to_string()),
+        part2: Some(fresh_ids.to_string()),
+        ..Default::default()
+    })
+}
+
+fn solve_day6(input: &str) -> Result<Answer, String> {
+    let mat: Vec<Vec<u8>> = input
+        .lines()
+        .filter(|l| !l.trim().is_empty())
+        .map(|l| l.as_bytes().to_vec())
+        .collect();
+    let total = day6::grand_total(&mat, MODE).map_err(|err| err.to_string())?;
+    Ok(Answer {
+        part2: Some(total.to_string()),
+        note: Some("only the column reading (part 2) is implemented".to_string()),
+        ..Default::default()
+    })
+}
+
+fn solve_day7(input: &str) -> Result<Answer, String> {
+    let mat = matrix(input);
+    if !mat.iter().any(|l| l.contains(&b'S')) {
+        return Err("no S in the manifold".to_string());
+    }
+    let (line, col) = day7::find_start(&mat);
+    let timeline =
+        day7::propagate_quantum_tachyon(&mat, line, col, MODE).map_err(|err| err.to_string())?;
+    Ok(Answer {
+        part2: Some(timeline.to_string()),
+        grid: grid(day7::draw_beams(&mat, line, col)),
+        note: Some("only the timeline count (part 2) is implemented".to_string()),
+        ..Default::default()
+    })
+}
+
+fn solve_day8(input: &str) -> Result<Answer, String> {
+    let mut juctions = Vec::new();
+    for line in lines(input) {
+        let values: Vec<&str> = line.trim().splitn(3, ',').collect();
+        if values.len() != 3 {
+            return Err(format!("expected x,y,z in {line:?}"));
+        }
+        juctions.push(Pos::from(values).map_err(|err| err.to_string())?);
+    }
+    // the example connects 10 pairs, the real input 1000
+    let connection_limit = if juctions.len() <= 20 { 10 } else { 1000 };
+    let (res, _) =
+        day8::create_circuit(&juctions, connection_limit, MODE).map_err(|err| err.to_string())?;
+    let (_, wall) = day8::create_circuit(&juctions, 0, MODE).map_err(|err| err.to_string())?;
+    Ok(Answer {
+        part1: Some(res.to_string()),
+        part2: Some(wall.to_string()),
+        ..Default::default()
+    })
+}
+
+fn solve_day9(input: &str) -> Result<Answer, String> {
+    let mut positions = Vec::new();
+    for line in lines(input) {
+        positions.push(day9::parse_line(&line.trim().to_string()).map_err(|err| err.to_string())?);
+    }
+    if positions.is_empty() {
+        return Err("no red tile".to_string());
+    }
+    let (_, part1) = day9::best_rectangle(&positions, MODE).map_err(|err| err.to_string())?;
+
+    // tiles at both ends of the i64 range span more than an i64
+    let span = |min: i64, max: i64| {
+        max.checked_sub(min)
+            .and_then(|d| d.checked_add(1))
+            .ok_or_else(|| format!("tiles from {min} to {max} are too far apart"))
+    };
+    let width = span(
+        positions.iter().map(|p| p.x).min().unwrap(),
+        positions.iter().map(|p| p.x).max().unwrap(),
+    )?;
+    let height = span(
+        positions.iter().map(|p| p.y).min().unwrap(),
+        positions.iter().map(|p| p.y).max().unwrap(),
+    )?;
+
+    let mut answer = Answer {
+        part1: Some(part1.to_string()),
+        grid: grid(day9::draw_polygon(
+            &positions,
+            (width.max(height) - 1) / MAX_GRID_WIDTH + 1,
+        )),
+        ..Default::default()
+    };
+    // part 2 rasterize the whole polygon
+    if width.saturating_mul(height) <= MAX_POLYGON_CELLS {
+        let (_, part2) =
+            day9::best_rectangle_part2(&positions, MODE).map_err(|err| err.to_string())?;
+        answer.part2 = Some(part2.to_string());
+    } else {
+        answer.note = Some(format!(
+            "part 2 skipped: a {width}x{height} polygon is too big to rasterize in the browser"
+        ));
+    }
+    Ok(answer)
+}
+
+// solvers are keyed by year then day
+pub fn solve(year: u32, day: u32, input: &str) -> Result<Answer, String> {
+    match (year, day) {
+        (2025, 1) => solve_day1(input),
+        (2025, 2) => solve_day2(input),
+        (2025, 3) => solve_day3(input),
+        (2025, 4) => solve_day4(input),
+        (2025, 5) => solve_day5(input),
+        (2025, 6) => solve_day6(input),
+        (2025, 7) => solve_day7(input),
+        (2025, 8) => solve_day8(input),
+        (2025, 9) => solve_day9(input),
+        _ => Err(format!("{year} day {day} is not solved yet")),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_json() {
+        let answer = Answer {
+            part1: Some("1".to_string()),
+            grid: Some(vec!["a\"b".to_string()]),
+            ..Default::default()
+        };
+        assert_eq!(
+            answer.to_json(),
+            r#"{"part1":"1","part2":null,"grid":["a\"b"],"note":null,"error":null}"#
+        );
+        assert_eq!(
+            error_json("bad\nline"),
+            r#"{"part1":null,"part2":null,"grid":null,"note":null,"error":"bad\nline"}"#
+        );
+    }
+
+    #[test]
+    fn test_solve_examples() {
+        let answer = solve(2025, 1, "L68\nL30\nR48\nL5\nR60\nL55\nL1\nL99\nR14\nL82\n").unwrap();
+        assert_eq!(answer.part1.unwrap(), "3");
+        assert_eq!(answer.part2.unwrap(), "6");
+
+        let answer = solve(
+            2025,
+            3,
+            "987654321111111\n811111111111119\n234234234234278\n818181911112111",
+        )
+        .unwrap();
+        assert_eq!(answer.part1.unwrap(), "357");
+        assert_eq!(answer.part2.unwrap(), "3121910778619");
+
+        let answer = solve(2025, 2, "11-22,20-33").unwrap();
+        assert_eq!(answer.part2.unwrap(), "66");
+        assert_eq!(
+            answer.note.unwrap(),
+            "overlapping ranges counted once: 11-22 and 20-33"
+        );
+        assert_eq!(
+            solve(2025, 2, "11-22,\n33-20").unwrap_err(),
+            "byte 7: reversed range '33-20'"
+        );
+
+        let answer = solve(2025, 5, "3-5\n10-14\n16-20\n12-18\n\n1\n5\n8\n11\n17\n32\n").unwrap();
+        assert_eq!(answer.part1.unwrap(), "3");
+        assert_eq!(answer.part2.unwrap(), "14");
+        let answer = solve(
+            2025,
+            5,
+            "3-5\r\n10-14\r\n16-20\r\n12-18\r\n\r\n1\r\n5\r\n8\r\n11\r\n17\r\n32\r\n",
+        )
+        .unwrap();
+        assert_eq!(answer.part1.unwrap(), "3");
+        assert_eq!(answer.part2.unwrap(), "14");
+
+        let answer = solve(2025, 9, "7,1\n11,1\n11,7\n9,7\n9,5\n2,5\n2,3\n7,3\n").unwrap();
+        assert_eq!(answer.part1.unwrap(), "50");
+        assert_eq!(answer.part2.unwrap(), "24");
+        assert_eq!(answer.grid.unwrap().len(), 7);
+    }
+
+    #[test]
+    fn test_solve_errors() {
+        assert!(solve(2025, 1, "X12").is_err());
+        assert!(solve(2025, 1, "L").is_err());
+        assert!(solve(2025, 3, "12").is_err());
+        assert!(solve(2025, 5, "3-5").is_err());
+        assert!(solve(2025, 7, "...").is_err());
+        assert_eq!(
+            solve(2025, 9, "-9223372036854775808,0\n9223372036854775807,0").unwrap_err(),
+            "tiles from -9223372036854775808 to 9223372036854775807 are too far apart"
+        );
+        assert!(solve(2025, 12, "").is_err());
+        assert!(solve(2024, 1, "L1").is_err());
+    }
+}
@@ -0,0 +1,69 @@
+// run with `node --test tests/` after ./build.sh
+import { test } from "node:test";
+import assert from "node:assert/strict";
+import { readFile } from "node:fs/promises";
+import { loadSolver } from "../www/aoc.js";
+
+const solve = await loadSolver(await readFile(new URL("../www/aoc_wasm.wasm", import.meta.url)));
+
+test("day1 example", () => {
+  const answer = solve(2025, 1, "L68\nL30\nR48\nL5\nR60\nL55\nL1\nL99\nR14\nL82\n");
+  assert.equal(answer.part1, "3");
+  assert.equal(answer.part2, "6");
+  assert.equal(answer.error, null);
+});
+
+test("day2 example", () => {
+  const answer = solve(2025, 2, "11-22,95-115,998-1012,1188511880-1188511890,222220-222224,\n"
+    + "1698522-1698528,446443-446449,38593856-38593862,565653-565659,\n"
+    + "824824821-824824827,2121212118-2121212124");
+  assert.equal(answer.part1, "1227775554");
+  assert.equal(answer.part2, "4174379265");
+});
+
+test("day3 example", () => {
+  const answer = solve(2025, 3, "987654321111111\n811111111111119\n234234234234278\n818181911112111\n");
+  assert.equal(answer.part1, "357");
+  assert.equal(answer.part2, "3121910778619");
+});
+
+test("day4 example with grid", () => {
+  const answer = solve(2025, 4, "..@@.@@@@.\n@@@.@.@.@@\n@@@@@.@.@@\n@.@@@@..@.\n@@.@@@@.@@\n"
+    + ".@@@@@@@.@\n.@.@.@.@@@\n@.@@@.@@@@\n.@@@@@@@@.\n@.@.@@@.@.\n");
+  assert.equal(answer.part1, "13");
+  assert.equal(answer.part2, "43");
+  assert.equal(answer.grid[0], "..xx.xx@x.");
+});
+
+test("day5 example with windows line endings", () => {
+  const answer = solve(2025, 5, "3-5\r\n10-14\r\n16-20\r\n12-18\r\n\r\n1\r\n5\r\n8\r\n11\r\n17\r\n32\r\n");
+  assert.equal(answer.part1, "3");
+  assert.equal(answer.part2, "14");
+});
+
+test("day6 example", () => {
+  const answer = solve(2025, 6, "123 328  51 64 \n 45 64  387 23 \n  6 98  215 314\n*   +   *   +  \n");
+  assert.equal(answer.part2, "3263827");
+});
+
+test("day7 example with beams", () => {
+  const answer = solve(2025, 7, ".......S.......\n...............\n.......^.......\n"
+    + "...............\n......^.^......\n...............\n");
+  assert.equal(answer.part2, "4");
+  assert.equal(answer.grid[5], ".....|.|.|.....");
+});
+
+test("day9 example with polygon", () => {
+  const answer = solve(2025, 9, "7,1\n11,1\n11,7\n9,7\n9,5\n2,5\n2,3\n7,3\n");
+  assert.equal(answer.part1, "50");
+  assert.equal(answer.part2, "24");
+  assert.equal(answer.grid.length, 7);
+});
+
+test("errors are reported, not thrown", () => {
+  assert.match(solve(2025, 1, "X12").error, /line 1: invalid instruction/);
+  assert.match(solve(2025, 10, "").error, /not solved yet/);
+  assert.match(solve(2024, 1, "").error, /2024 day 1 is not solved yet/);
+  // the solver is still usable afterwards
+  assert.equal(solve(2025, 3, "987654321111111\n").part1, "98");
+});
@@ -0,0 +1,24 @@
+// javascript side of the ABI exported by src/lib.rs, used by the page and the node tests
+export async function loadSolver(source) {
+  const { instance } = await WebAssembly.instantiate(source, {});
+  const wasm = instance.exports;
+  const encoder = new TextEncoder();
+  const decoder = new TextDecoder();
+
+  return function solve(year, day, input) {
+    const bytes = encoder.encode(input);
+    const ptr = wasm.alloc(bytes.length);
+    new Uint8Array(wasm.memory.buffer, ptr, bytes.length).set(bytes);
+
+    let len;
+    try {
+      len = wasm.solve(year, day, ptr, bytes.length);
+    } catch (err) {
+      // a rust panic traps, the input buffer is leaked on purpose
+      return { part1: null, part2: null, grid: null, note: null, error: `solver crashed: ${err}` };
+    }
+    wasm.dealloc(ptr, bytes.length);
+    const output = new Uint8Array(wasm.memory.buffer, wasm.output_ptr(), len);
+    return JSON.parse(decoder.decode(output));
+  };
+}